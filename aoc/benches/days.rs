@@ -0,0 +1,78 @@
+use std::{env, ops::RangeInclusive};
+
+use aoc::{
+    config::{Config, Source},
+    registry::{self, Day},
+};
+use aoc_common::{string_from_file, Params};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// The examples are smaller than the real puzzles, so day 9 looks back 5 numbers instead of 25.
+fn example_params(day: u32) -> Params {
+    let mut params = Params::new();
+    if day == 9 {
+        params.set("preamble", 5);
+    }
+    params
+}
+
+/// The days whose part 2 gets an example of its own because it can't run on the first one, like
+/// day 14's, which floats 34 bits of every address.
+const PART2_EXAMPLES: &[u32] = &[14];
+
+fn bench_input(
+    c: &mut Criterion,
+    day: &Day,
+    name: &str,
+    input: &str,
+    params: &Params,
+    parts: RangeInclusive<u32>,
+) {
+    let mut group = c.benchmark_group(format!("day{}", day.day));
+    // a few of the parts take seconds, keep the whole suite bearable
+    group.sample_size(10);
+
+    group.bench_with_input(BenchmarkId::new("parse", name), input, |b, input| {
+        b.iter(|| day.solver.parse_with(input, params).unwrap())
+    });
+
+    let parsed = day.solver.parse_with(input, params).unwrap();
+    for part in parts {
+        group.bench_with_input(
+            BenchmarkId::new(format!("part{}", part), name),
+            &parsed,
+            |b, parsed| b.iter(|| day.solver.part(parsed, part)),
+        );
+    }
+
+    group.finish();
+}
+
+fn days(c: &mut Criterion) {
+    // aoc.toml, the inputs and the examples are all relative to the workspace root
+    env::set_current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/..")).unwrap();
+    let config = Config::load(None, None, &[]).unwrap();
+
+    for day in registry::DAYS {
+        let params = example_params(day.day);
+        let example = string_from_file(format!("day{}/example.txt", day.day));
+        if PART2_EXAMPLES.contains(&day.day) {
+            bench_input(c, day, "example", &example, &params, 1..=1);
+            let example = string_from_file(format!("day{}/example2.txt", day.day));
+            bench_input(c, day, "example2", &example, &params, 2..=2);
+        } else {
+            bench_input(c, day, "example", &example, &params, 1..=2);
+        }
+
+        let input = match config.source(day.day) {
+            Source::Inline(input) => input,
+            Source::File(path) if path.exists() => string_from_file(path),
+            // no real input here, only the example then
+            _ => continue,
+        };
+        bench_input(c, day, "input", &input, &config.params(day.day), 1..=2);
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,197 @@
+//! Counting what the solutions allocate. Built with `--features alloc-stats`, the runner's global
+//! allocator is a `Counting` one, and `aoc run --memory` says how many allocations parsing and each
+//! part made, how many bytes they asked for and the most they held at once.
+//!
+//! The counters are shared by all threads, so only measure one thing at a time.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+/// The system allocator, keeping count.
+pub struct Counting {
+    allocations: AtomicUsize,
+    allocated: AtomicUsize,
+    live: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+pub static ALLOCATOR: Counting = Counting::new();
+
+/// Whether this build counts allocations at all.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+/// What something allocated while it ran.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub allocations: usize,
+    /// A reallocation counts as one allocation of its new size.
+    pub bytes: usize,
+    /// The most it held at once, above what was held when it started.
+    pub peak: usize,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            bytes(self.bytes),
+            bytes(self.peak)
+        )
+    }
+}
+
+/// `n` bytes in the largest unit that keeps it at 1 or more.
+pub fn bytes(n: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if n < 1024 {
+        return format!("{} B", n);
+    }
+    let mut size = n as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+impl Counting {
+    pub const fn new() -> Counting {
+        Counting {
+            allocations: AtomicUsize::new(0),
+            allocated: AtomicUsize::new(0),
+            live: AtomicUsize::new(0),
+            peak: AtomicUsize::new(0),
+        }
+    }
+
+    fn grow(&self, size: usize) {
+        self.allocations.fetch_add(1, Relaxed);
+        self.allocated.fetch_add(size, Relaxed);
+        let live = self.live.fetch_add(size, Relaxed) + size;
+        self.peak.fetch_max(live, Relaxed);
+    }
+
+    /// Runs `f`, counting what it allocates.
+    pub fn measure<T>(&self, f: impl FnOnce() -> T) -> (T, Stats) {
+        let allocations = self.allocations.load(Relaxed);
+        let allocated = self.allocated.load(Relaxed);
+        let live = self.live.load(Relaxed);
+        self.peak.store(live, Relaxed);
+
+        let result = f();
+        let stats = Stats {
+            allocations: self.allocations.load(Relaxed) - allocations,
+            bytes: self.allocated.load(Relaxed) - allocated,
+            peak: self.peak.load(Relaxed).saturating_sub(live),
+        };
+        (result, stats)
+    }
+}
+
+impl Default for Counting {
+    fn default() -> Counting {
+        Counting::new()
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            self.grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        self.live.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            self.live.fetch_sub(layout.size(), Relaxed);
+            self.grow(new_size);
+        }
+        new
+    }
+}
+
+/// Runs `f`, counting what it allocates when this build has the counting allocator.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Stats>) {
+    #[cfg(feature = "alloc-stats")]
+    {
+        let (result, stats) = ALLOCATOR.measure(f);
+        (result, Some(stats))
+    }
+    #[cfg(not(feature = "alloc-stats"))]
+    {
+        (f(), None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let counting = Counting::new();
+        let small = Layout::from_size_align(100, 8).unwrap();
+        let big = Layout::from_size_align(1000, 8).unwrap();
+
+        let (_, stats) = counting.measure(|| unsafe {
+            let a = counting.alloc(small);
+            let b = counting.alloc_zeroed(big);
+            counting.dealloc(b, big);
+            let a = counting.realloc(a, small, 300);
+            counting.dealloc(a, Layout::from_size_align(300, 8).unwrap());
+        });
+        assert_eq!(
+            stats,
+            Stats {
+                allocations: 3,
+                bytes: 1400,
+                peak: 1100,
+            }
+        );
+
+        // the peak is counted from what was held when measuring started
+        let kept = unsafe { counting.alloc(big) };
+        let (_, stats) = counting.measure(|| unsafe {
+            let a = counting.alloc(small);
+            counting.dealloc(a, small);
+        });
+        assert_eq!(stats.peak, 100);
+        unsafe { counting.dealloc(kept, big) };
+        assert_eq!(
+            stats.to_string(),
+            "1 allocations, 100 B allocated, 100 B peak"
+        );
+    }
+
+    #[test]
+    fn test_bytes() {
+        assert_eq!(bytes(0), "0 B");
+        assert_eq!(bytes(1023), "1023 B");
+        assert_eq!(bytes(1536), "1.5 KiB");
+        assert_eq!(bytes(160_000_000), "152.6 MiB");
+    }
+}
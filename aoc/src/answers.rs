@@ -0,0 +1,125 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use serde::Deserialize;
+
+/// The verified answers on the real input, as kept in `answers.toml`:
+///
+/// ```toml
+/// [day.9]
+/// part1 = "675280050"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<u32, Parts>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Parts {
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct File {
+    #[serde(default)]
+    day: BTreeMap<String, Parts>,
+}
+
+impl Answers {
+    /// Reads the answers at `path`, or none at all if there is no such file yet.
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        Answers::from_toml(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    fn from_toml(contents: &str) -> Result<Answers, String> {
+        let file: File = toml::from_str(contents).map_err(|e| e.to_string())?;
+        let mut answers = Answers::default();
+        for (day, parts) in file.day {
+            let day = day
+                .parse::<u32>()
+                .ok()
+                .filter(|d| (1..=25).contains(d))
+                .ok_or_else(|| format!("[day.{}] is not a day between 1 and 25", day))?;
+            answers.days.insert(day, parts);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_toml())
+            .map_err(|e| format!("Could not write {}: {}", path.display(), e))
+    }
+
+    // written by hand to keep the days in numerical order
+    fn to_toml(&self) -> String {
+        let mut toml = String::new();
+        for (day, parts) in &self.days {
+            if !toml.is_empty() {
+                toml.push('\n');
+            }
+            toml.push_str(&format!("[day.{}]\n", day));
+            for (name, answer) in [("part1", &parts.part1), ("part2", &parts.part2)] {
+                if let Some(answer) = answer {
+                    let value = toml::Value::String(answer.clone());
+                    toml.push_str(&format!("{} = {}\n", name, value));
+                }
+            }
+        }
+        toml
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        let parts = self.days.get(&day)?;
+        match part {
+            1 => parts.part1.as_deref(),
+            2 => parts.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn set(&mut self, day: u32, part: u32, answer: String) {
+        let parts = self.days.entry(day).or_default();
+        match part {
+            1 => parts.part1 = Some(answer),
+            2 => parts.part2 = Some(answer),
+            _ => panic!("There is no part {}", part),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers() {
+        let mut answers = Answers::default();
+        answers.set(21, 2, "mxmxvkd,sqjhc,fvjkl".to_string());
+        answers.set(9, 1, "127".to_string());
+        answers.set(9, 2, "62".to_string());
+
+        let toml = answers.to_toml();
+        assert_eq!(
+            toml,
+            "[day.9]\npart1 = \"127\"\npart2 = \"62\"\n\n[day.21]\npart2 = \"mxmxvkd,sqjhc,fvjkl\"\n"
+        );
+        let answers = Answers::from_toml(&toml).unwrap();
+        assert_eq!(answers.get(9, 1), Some("127"));
+        assert_eq!(answers.get(21, 1), None);
+        assert_eq!(answers.get(21, 2), Some("mxmxvkd,sqjhc,fvjkl"));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(Answers::from_toml("[day.0]\npart1 = \"1\"").is_err());
+        assert!(Answers::from_toml("[day.1]\npart3 = \"1\"").is_err());
+    }
+}
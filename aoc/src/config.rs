@@ -0,0 +1,254 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::{string_from_file, string_from_stdin, Params, ParseError};
+use serde::Deserialize;
+
+pub const DEFAULT_CONFIG: &str = "aoc.toml";
+pub const DEFAULT_INPUT_DIR: &str = "input/2020";
+pub const DEFAULT_ANSWERS: &str = "answers.toml";
+
+/// The contents of `aoc.toml`, e.g.
+///
+/// ```toml
+/// input_dir = "input/2020"
+/// answers = "answers.toml"
+///
+/// [day.9]
+/// preamble = 25
+///
+/// [day.15]
+/// input = "0,13,1,16,6,17"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct File {
+    input_dir: Option<PathBuf>,
+    answers: Option<PathBuf>,
+    #[serde(default)]
+    day: BTreeMap<String, DayFile>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DayFile {
+    /// The puzzle input itself, instead of a file in the input directory.
+    input: Option<String>,
+    #[serde(flatten)]
+    params: BTreeMap<String, usize>,
+}
+
+/// A puzzle parameter given on the command line, like `9.preamble=5`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamArg {
+    pub day: u32,
+    pub key: String,
+    pub value: usize,
+}
+
+pub fn parse_param(input: &str) -> Result<ParamArg, String> {
+    let invalid = || format!("'{}' is not a parameter like '9.preamble=5'", input);
+    let (name, value) = input.split_once('=').ok_or_else(invalid)?;
+    let (day, key) = name.split_once('.').ok_or_else(invalid)?;
+
+    Ok(ParamArg {
+        day: day.parse().map_err(|_| invalid())?,
+        key: key.to_string(),
+        value: value.parse().map_err(|_| invalid())?,
+    })
+}
+
+/// Where a day's input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Inline(String),
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// `-` means stdin, anything else is a path.
+    pub fn from_arg(arg: &Path) -> Source {
+        if arg == Path::new("-") {
+            Source::Stdin
+        } else {
+            Source::File(arg.to_path_buf())
+        }
+    }
+
+    /// The input, or `None` when its file does not exist.
+    pub fn read(&self) -> Option<String> {
+        match self {
+            Source::Inline(input) => Some(input.clone()),
+            Source::File(filename) if !filename.exists() => None,
+            Source::File(filename) => Some(string_from_file(filename)),
+            Source::Stdin => Some(string_from_stdin()),
+        }
+    }
+
+    /// Points `err` at the file the input came from.
+    pub fn locate(&self, err: ParseError) -> ParseError {
+        match self {
+            Source::Inline(_) => err,
+            Source::File(filename) => err.in_file(filename.display().to_string()),
+            Source::Stdin => err.in_file("<stdin>"),
+        }
+    }
+}
+
+/// Settings from the config file, with command line flags and environment variables on top.
+#[derive(Debug)]
+pub struct Config {
+    pub input_dir: PathBuf,
+    /// The recorded answers that `aoc verify` checks against.
+    pub answers: PathBuf,
+    inputs: BTreeMap<u32, String>,
+    params: BTreeMap<u32, Params>,
+}
+
+impl Config {
+    /// Reads `path`, or `aoc.toml` when no path is given and it exists, and applies the overrides.
+    pub fn load(
+        path: Option<&Path>,
+        input_dir: Option<PathBuf>,
+        overrides: &[ParamArg],
+    ) -> Result<Config, String> {
+        let file = match path {
+            Some(path) => Config::read(path)?,
+            None if Path::new(DEFAULT_CONFIG).exists() => Config::read(Path::new(DEFAULT_CONFIG))?,
+            None => File::default(),
+        };
+        let mut config = Config::from_file(file)?;
+
+        if let Some(input_dir) = input_dir {
+            config.input_dir = input_dir;
+        }
+        for param in overrides {
+            config
+                .params
+                .entry(param.day)
+                .or_default()
+                .set(param.key.clone(), param.value);
+        }
+
+        Ok(config)
+    }
+
+    fn read(path: &Path) -> Result<File, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        toml::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    fn from_file(file: File) -> Result<Config, String> {
+        let mut config = Config {
+            input_dir: file
+                .input_dir
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR)),
+            answers: file
+                .answers
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_ANSWERS)),
+            inputs: BTreeMap::new(),
+            params: BTreeMap::new(),
+        };
+
+        for (day, settings) in file.day {
+            let day = day
+                .parse::<u32>()
+                .ok()
+                .filter(|d| (1..=25).contains(d))
+                .ok_or_else(|| format!("[day.{}] is not a day between 1 and 25", day))?;
+            if let Some(input) = settings.input {
+                config.inputs.insert(day, input);
+            }
+            let params = config.params.entry(day).or_default();
+            for (key, value) in settings.params {
+                params.set(key, value);
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn source(&self, day: u32) -> Source {
+        match self.inputs.get(&day) {
+            Some(input) => Source::Inline(input.clone()),
+            None => Source::File(self.input_dir.join(format!("day{}.txt", day))),
+        }
+    }
+
+    pub fn params(&self, day: u32) -> Params {
+        self.params.get(&day).cloned().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn from_str(contents: &str) -> Result<Config, String> {
+        Config::from_file(toml::from_str(contents).map_err(|e| e.to_string())?)
+    }
+
+    #[test]
+    fn test_config() {
+        let config = from_str(
+            r#"
+            input_dir = "inputs"
+            answers = "inputs/answers.toml"
+
+            [day.9]
+            preamble = 5
+
+            [day.25]
+            input = "5764801\n17807724"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.source(1),
+            Source::File(PathBuf::from("inputs/day1.txt"))
+        );
+        assert_eq!(
+            config.source(25),
+            Source::Inline("5764801\n17807724".to_string())
+        );
+        assert_eq!(config.answers, PathBuf::from("inputs/answers.toml"));
+        assert_eq!(config.params(9).get("preamble"), Some(5));
+        assert_eq!(config.params(10).get("preamble"), None);
+    }
+
+    #[test]
+    fn test_source_from_arg() {
+        assert_eq!(Source::from_arg(Path::new("-")), Source::Stdin);
+        assert_eq!(
+            Source::from_arg(Path::new("day7/example.txt")),
+            Source::File(PathBuf::from("day7/example.txt"))
+        );
+    }
+
+    #[test]
+    fn test_config_errors() {
+        assert!(from_str("input = \"x\"").is_err());
+        assert!(from_str("[day.26]\ninput = \"x\"").is_err());
+        assert!(from_str("[day.9]\npreamble = -5").is_err());
+    }
+
+    #[test]
+    fn test_parse_param() {
+        assert_eq!(
+            parse_param("9.preamble=5"),
+            Ok(ParamArg {
+                day: 9,
+                key: "preamble".to_string(),
+                value: 5
+            })
+        );
+        assert!(parse_param("9.preamble").is_err());
+        assert!(parse_param("preamble=5").is_err());
+        assert!(parse_param("9.preamble=-5").is_err());
+    }
+}
@@ -0,0 +1,1552 @@
+//! Seeded generators for every day's puzzle input, for benchmarking on inputs of any size and for
+//! finding the assumptions the solutions make about their input.
+//!
+//! By default a generator copies the habits every real input has even where the puzzle doesn't
+//! promise them, like day 10 never having more than four adapters one jolt apart in a row. With
+//! `adversarial` it only keeps to what the puzzle text allows, which is where the solutions that
+//! lean on those habits give up. Days without such habits generate the same input either way.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+    ops::RangeInclusive,
+};
+
+use aoc_common::{assign, math};
+
+/// SplitMix64, which is small and gives the same numbers for a seed on every platform and with
+/// every version of the dependencies, so a seed always names the same input.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = (range.end() - range.start()) as u128 + 1;
+        range.start() + ((self.next_u64() as u128 * span) >> 64) as i64
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1u64 << 53) as f64
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+
+    fn letter(&mut self) -> char {
+        (b'a' + self.below(26) as u8) as char
+    }
+}
+
+/// What the size of a day's input counts, and the sizes its generator can make.
+pub struct Size {
+    pub what: &'static str,
+    pub range: RangeInclusive<usize>,
+    pub default: usize,
+}
+
+pub struct Generator {
+    pub day: u32,
+    /// `None` for days whose input always has the same size.
+    pub size: Option<Size>,
+    generate: fn(&mut Rng, usize, bool) -> String,
+}
+
+impl Generator {
+    /// The input for `seed`, `size` being the number of whatever the day's size counts, or its
+    /// default when `None`.
+    pub fn generate(
+        &self,
+        seed: u64,
+        size: Option<usize>,
+        adversarial: bool,
+    ) -> Result<String, String> {
+        let size = match (&self.size, size) {
+            (None, None) => 0,
+            (None, Some(_)) => return Err(format!("Day {}'s input has a fixed size", self.day)),
+            (Some(s), None) => s.default,
+            (Some(s), Some(size)) if s.range.contains(&size) => size,
+            (Some(s), Some(_)) => {
+                return Err(format!(
+                    "Day {}'s size is the {}, between {} and {}",
+                    self.day,
+                    s.what,
+                    s.range.start(),
+                    s.range.end()
+                ))
+            }
+        };
+        Ok((self.generate)(&mut Rng::new(seed), size, adversarial))
+    }
+}
+
+macro_rules! generator {
+    ($day:expr, $generate:ident) => {
+        Generator {
+            day: $day,
+            size: None,
+            generate: $generate,
+        }
+    };
+    ($day:expr, $generate:ident, $what:expr, $range:expr, $default:expr) => {
+        Generator {
+            day: $day,
+            size: Some(Size {
+                what: $what,
+                range: $range,
+                default: $default,
+            }),
+            generate: $generate,
+        }
+    };
+}
+
+// the default sizes are those of the real inputs
+pub static GENERATORS: &[Generator] = &[
+    generator!(1, expenses, "number of expenses", 5..=975, 200),
+    generator!(2, passwords, "number of passwords", 1..=100_000, 1000),
+    generator!(3, slope, "number of rows", 1..=100_000, 323),
+    generator!(4, passports, "number of passports", 1..=100_000, 290),
+    generator!(
+        5,
+        boarding_passes,
+        "number of boarding passes",
+        801..=1022,
+        850
+    ),
+    generator!(6, answers, "number of groups", 1..=100_000, 490),
+    generator!(7, bag_rules, "number of bag colours", 2..=594, 594),
+    generator!(8, boot_code, "number of instructions", 10..=100_000, 640),
+    generator!(9, xmas, "number of numbers", 26..=1200, 1000),
+    generator!(10, adapters, "number of adapters", 1..=10_000, 95),
+    generator!(11, seat_layout, "number of rows and columns", 1..=250, 95),
+    generator!(12, navigation, "number of instructions", 1..=100_000, 780),
+    generator!(13, bus_notes, "number of buses", 1..=12, 9),
+    generator!(14, docking_program, "number of masks", 1..=10_000, 100),
+    generator!(
+        15,
+        starting_numbers,
+        "number of starting numbers",
+        1..=1000,
+        6
+    ),
+    generator!(
+        16,
+        ticket_notes,
+        "number of nearby tickets",
+        26..=10_000,
+        240
+    ),
+    generator!(17, pocket_slice, "number of rows and columns", 1..=30, 8),
+    generator!(19, messages, "number of messages", 1..=10_000, 400),
+    generator!(20, image_tiles, "number of tiles per side", 1..=12, 12),
+    generator!(21, foods, "number of foods", 1..=1000, 40),
+    generator!(22, decks, "number of cards per player", 1..=100, 25),
+    generator!(23, cup_labels),
+    generator!(24, tile_paths, "number of tiles", 1..=10_000, 400),
+    generator!(
+        25,
+        public_keys,
+        "largest loop size",
+        1..=20_201_226,
+        20_201_226
+    ),
+];
+
+pub fn find(day: u32) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+fn lines<T: ToString>(items: impl IntoIterator<Item = T>) -> String {
+    items
+        .into_iter()
+        .map(|item| item.to_string() + "\n")
+        .collect()
+}
+
+/// One pair and one triple of entries add up to 2020. Real inputs are mostly entries over 1010,
+/// which can't be in either, so those are the only ones; adversarial inputs have any entries,
+/// including the same one twice.
+fn expenses(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    let mut entries: Vec<i64>;
+    if adversarial {
+        entries = (0..size).map(|_| rng.range(0..=2020)).collect();
+        let a = rng.range(0..=2020);
+        let x = rng.range(0..=2020);
+        let y = rng.range(0..=2020 - x);
+        for (i, entry) in [a, 2020 - a, x, y, 2020 - x - y].iter().enumerate() {
+            entries[i] = *entry;
+        }
+    } else {
+        let small = loop {
+            let a = rng.range(1..=1009);
+            let x = rng.range(2..=1009);
+            let y = rng.range(1011 - x..=1009);
+            let small = [a, x, y, 2020 - x - y];
+            let distinct: HashSet<i64> = small.iter().copied().collect();
+            // a with any two of them, or 2020 - a with any two, would make a second triple
+            let (z, b) = (small[3], 2020 - a);
+            let triples = [
+                a + x + y,
+                a + x + z,
+                a + y + z,
+                b + x + y,
+                b + x + z,
+                b + y + z,
+            ];
+            if z <= 1009 && distinct.len() == 4 && !triples.contains(&2020) {
+                break small;
+            }
+        };
+        entries = small.to_vec();
+        entries.push(2020 - small[0]);
+
+        // and an entry over 1010 would make a pair or a triple with the small entries
+        let mut taken: HashSet<i64> = entries.iter().copied().collect();
+        for (i, s) in small.iter().enumerate() {
+            taken.insert(2020 - s);
+            for t in &small[i + 1..] {
+                taken.insert(2020 - s - t);
+            }
+        }
+        let mut large: Vec<i64> = (1011..=2000).filter(|e| !taken.contains(e)).collect();
+        rng.shuffle(&mut large);
+        entries.extend(large.into_iter().take(size - 5));
+    }
+
+    rng.shuffle(&mut entries);
+    lines(entries)
+}
+
+/// Policies and passwords, where real policies never point past the end of their password.
+fn passwords(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        let len = rng.range(2..=20);
+        let chr = rng.letter();
+        // real passwords lean towards their policy's letter
+        let password: String = (0..len)
+            .map(|_| if rng.chance(0.4) { chr } else { rng.letter() })
+            .collect();
+        let (lo, hi) = if adversarial {
+            let lo = rng.range(1..=len + 3);
+            (lo, rng.range(lo..=len + 3))
+        } else {
+            let lo = rng.range(1..=len - 1);
+            (lo, rng.range(lo + 1..=len))
+        };
+        writeln!(input, "{}-{} {}: {}", lo, hi, chr, password).unwrap();
+    }
+    input
+}
+
+/// A map of trees 31 columns wide, or of any width from 1 to 40 when adversarial.
+fn slope(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    let width = if adversarial { rng.range(1..=40) } else { 31 };
+    let mut input = String::new();
+    for row in 0..size {
+        for col in 0..width {
+            let tree = (row, col) != (0, 0) && rng.chance(0.25);
+            input.push(if tree { '#' } else { '.' });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+const EYE_COLOURS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+fn passport_field(rng: &mut Rng, field: &str, valid: bool) -> String {
+    let digits = |rng: &mut Rng, n: usize| -> String {
+        (0..n).map(|_| rng.range(0..=9).to_string()).collect()
+    };
+    let hex = |rng: &mut Rng, n: usize| -> String {
+        (0..n)
+            .map(|_| *rng.pick(b"0123456789abcdef") as char)
+            .collect()
+    };
+
+    match (field, valid) {
+        ("byr", true) => rng.range(1920..=2002).to_string(),
+        ("iyr", true) => rng.range(2010..=2020).to_string(),
+        ("eyr", true) => rng.range(2020..=2030).to_string(),
+        ("byr", false) | ("iyr", false) | ("eyr", false) => rng.range(1900..=2040).to_string(),
+        ("hgt", true) if rng.chance(0.5) => format!("{}cm", rng.range(150..=193)),
+        ("hgt", true) => format!("{}in", rng.range(59..=76)),
+        ("hgt", false) => match rng.below(3) {
+            0 => format!("{}cm", rng.range(100..=250)),
+            1 => format!("{}in", rng.range(40..=90)),
+            _ => rng.range(50..=200).to_string(),
+        },
+        ("hcl", true) => format!("#{}", hex(rng, 6)),
+        ("hcl", false) => match rng.below(3) {
+            0 => hex(rng, 6),
+            1 => format!("#{}", hex(rng, 5)),
+            _ => "z".to_string(),
+        },
+        ("ecl", true) => rng.pick(&EYE_COLOURS).to_string(),
+        ("ecl", false) => rng.pick(&["gmt", "utc", "lzr", "xry", "dne"]).to_string(),
+        ("pid", true) => digits(rng, 9),
+        ("pid", false) => match rng.below(2) {
+            0 => digits(rng, 8),
+            _ => digits(rng, 10),
+        },
+        _ => rng.range(100..=350).to_string(),
+    }
+}
+
+/// Passports missing some fields and with some values out of range. Adversarial ones also have
+/// fields that are no number at all where one is expected, and fields no passport should have.
+fn passports(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    const FIELDS: [&str; 8] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid", "cid"];
+
+    let mut passports = vec![];
+    for _ in 0..size {
+        let mut fields = vec![];
+        for field in FIELDS {
+            let present = if field == "cid" { 0.5 } else { 0.9 };
+            if !rng.chance(present) {
+                continue;
+            }
+            let value = if adversarial && rng.chance(0.05) {
+                (0..rng.range(1..=6)).map(|_| rng.letter()).collect()
+            } else {
+                let valid = rng.chance(0.7);
+                passport_field(rng, field, valid)
+            };
+            fields.push(format!("{}:{}", field, value));
+        }
+        if adversarial && rng.chance(0.05) {
+            fields.push(format!(
+                "{}:{}",
+                rng.pick(&["foo", "dob", "nat"]),
+                rng.letter()
+            ));
+        }
+        if fields.is_empty() {
+            fields.push(format!("cid:{}", rng.range(100..=350)));
+        }
+        rng.shuffle(&mut fields);
+
+        let mut passport = fields[0].clone();
+        for field in &fields[1..] {
+            passport.push(if rng.chance(0.3) { '\n' } else { ' ' });
+            passport.push_str(field);
+        }
+        passports.push(passport);
+    }
+    passports.join("\n\n") + "\n"
+}
+
+fn seat_code(id: i64) -> String {
+    let (row, col) = (id / 8, id % 8);
+    let mut code: String = (0..7)
+        .rev()
+        .map(|bit| if (row >> bit) & 1 == 1 { 'B' } else { 'F' })
+        .collect();
+    code.extend(
+        (0..3)
+            .rev()
+            .map(|bit| if (col >> bit) & 1 == 1 { 'R' } else { 'L' }),
+    );
+    code
+}
+
+/// A full flight but for one seat. Real flights are missing a few seats at the very front and
+/// back only, while adversarial ones can be missing any seats but the ones next to yours.
+fn boarding_passes(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    // the flight is the seats first..=first + size, with yours missing
+    let first = if adversarial {
+        rng.range(0..=1023 - size as i64)
+    } else {
+        rng.range((899 - size as i64).max(0)..=(1023 - size as i64).min(101))
+    };
+    let last = first + size as i64;
+    let yours = if adversarial {
+        rng.range(first + 1..=last - 1)
+    } else {
+        rng.range((first + 1).max(101)..=(last - 1).min(899))
+    };
+
+    let mut ids: Vec<i64> = (first..=last).filter(|id| *id != yours).collect();
+    rng.shuffle(&mut ids);
+    lines(ids.into_iter().map(seat_code))
+}
+
+/// Groups of people who mostly answer yes to the same questions.
+fn answers(rng: &mut Rng, size: usize, _adversarial: bool) -> String {
+    let mut groups = vec![];
+    for _ in 0..size {
+        let common: Vec<char> = (0..rng.range(0..=6)).map(|_| rng.letter()).collect();
+        let mut group = vec![];
+        for _ in 0..rng.range(1..=5) {
+            let mut person: Vec<char> = common.clone();
+            person.extend((0..rng.range(0..=8)).map(|_| rng.letter()));
+            if person.is_empty() {
+                person.push(rng.letter());
+            }
+            rng.shuffle(&mut person);
+            let mut seen = HashSet::new();
+            person.retain(|c| seen.insert(*c));
+            group.push(person.into_iter().collect::<String>());
+        }
+        groups.push(group.join("\n"));
+    }
+    groups.join("\n\n") + "\n"
+}
+
+const ADJECTIVES: [&str; 18] = [
+    "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+    "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy",
+];
+
+const COLOURS: [&str; 33] = [
+    "aqua",
+    "beige",
+    "black",
+    "blue",
+    "bronze",
+    "brown",
+    "chartreuse",
+    "coral",
+    "crimson",
+    "cyan",
+    "fuchsia",
+    "gold",
+    "gray",
+    "green",
+    "indigo",
+    "lavender",
+    "lime",
+    "magenta",
+    "maroon",
+    "olive",
+    "orange",
+    "plum",
+    "purple",
+    "red",
+    "salmon",
+    "silver",
+    "tan",
+    "teal",
+    "tomato",
+    "turquoise",
+    "violet",
+    "white",
+    "yellow",
+];
+
+/// Bag rules without cycles, the bags in layers that only hold bags of the layers below. Real
+/// rules nest eight bags deep, each bag holding bags of the next layer, with shiny gold three
+/// from the bottom, holding a few bags and held by every bag of the layer above. Adversarial
+/// rules can nest as deep as there are colours, which is deep enough for the number of bags
+/// inside to overflow.
+fn bag_rules(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    let mut bags: Vec<String> = ADJECTIVES
+        .iter()
+        .flat_map(|adjective| {
+            COLOURS
+                .iter()
+                .map(move |colour| format!("{} {}", adjective, colour))
+        })
+        .filter(|bag| bag != "shiny gold")
+        .collect();
+    rng.shuffle(&mut bags);
+    bags.truncate(size - 1);
+    bags.push("shiny gold".to_string());
+
+    let (depth, reach) = if adversarial { (size, 3) } else { (8, 1) };
+    let mut layered: Vec<(usize, String)> = bags
+        .into_iter()
+        .map(|bag| {
+            let layer = if bag == "shiny gold" && !adversarial {
+                5
+            } else {
+                rng.below(depth + 1)
+            };
+            (layer, bag)
+        })
+        .collect();
+    layered.sort();
+
+    let mut rules = vec![];
+    for (layer, bag) in &layered {
+        let inner: Vec<&String> = layered
+            .iter()
+            .filter(|(l, _)| (layer + 1..=layer + reach).contains(l))
+            .map(|(_, bag)| bag)
+            .collect();
+        let mut contents: Vec<&String> = if inner.is_empty() {
+            vec![]
+        } else {
+            let count = if bag == "shiny gold" && !adversarial {
+                rng.range(2..=4) as usize
+            } else {
+                rng.below(5)
+            };
+            (0..count).map(|_| *rng.pick(&inner)).collect()
+        };
+        if *layer == 4 && !adversarial {
+            contents.push(
+                &layered
+                    .iter()
+                    .find(|(_, bag)| bag == "shiny gold")
+                    .unwrap()
+                    .1,
+            );
+        }
+        contents.sort();
+        contents.dedup();
+
+        let contents: Vec<String> = contents
+            .into_iter()
+            .map(|inner| match rng.range(1..=5) {
+                1 => format!("1 {} bag", inner),
+                n => format!("{} {} bags", n, inner),
+            })
+            .collect();
+        if contents.is_empty() {
+            rules.push(format!("{} bags contain no other bags.", bag));
+        } else {
+            rules.push(format!("{} bags contain {}.", bag, contents.join(", ")));
+        }
+    }
+
+    rng.shuffle(&mut rules);
+    lines(rules)
+}
+
+/// The instructions that run from `0` until the program ends or loops.
+fn executed(code: &[(&str, i64)]) -> Vec<usize> {
+    let mut visited = vec![false; code.len()];
+    let mut path = vec![];
+    let mut pc = 0;
+    while pc < code.len() && !visited[pc] {
+        visited[pc] = true;
+        path.push(pc);
+        pc = match code[pc] {
+            ("jmp", offset) => (pc as i64 + offset) as usize,
+            _ => pc + 1,
+        };
+    }
+    path
+}
+
+/// For every instruction, whether the program run from there ends rather than loops.
+fn ends_from(code: &[(&str, i64)]) -> Vec<bool> {
+    let next = |pc: usize| match code[pc] {
+        ("jmp", offset) => pc as i64 + offset,
+        _ => pc as i64 + 1,
+    };
+    let mut ends: Vec<Option<bool>> = vec![None; code.len()];
+    let mut visiting = vec![false; code.len()];
+    for start in 0..code.len() {
+        // follow the program until it leaves, or gets somewhere already known or already on the way
+        let mut on_the_way = vec![];
+        let mut pc = start as i64;
+        let result = loop {
+            if !(0..code.len() as i64).contains(&pc) {
+                break true;
+            }
+            match ends[pc as usize] {
+                Some(result) => break result,
+                None if visiting[pc as usize] => break false,
+                None => {
+                    visiting[pc as usize] = true;
+                    on_the_way.push(pc as usize);
+                    pc = next(pc as usize);
+                }
+            }
+        };
+        for pc in on_the_way {
+            ends[pc] = Some(result);
+        }
+    }
+    ends.into_iter().map(|ends| ends.unwrap()).collect()
+}
+
+/// How many ways there are to make a looping program end by turning one `jmp` into a `nop` or one
+/// `nop` into a `jmp`. Only the instructions that run matter, and since they all lead into the
+/// loop, the program fixed at one ends exactly when the unfixed one run from its new next
+/// instruction does.
+fn fixes(code: &[(&str, i64)]) -> usize {
+    let ends = ends_from(code);
+    let ends_at = |pc: i64| !(0..code.len() as i64).contains(&pc) || ends[pc as usize];
+    executed(code)
+        .into_iter()
+        .filter(|&pc| match code[pc] {
+            ("jmp", _) => ends_at(pc as i64 + 1),
+            ("nop", offset) => ends_at(pc as i64 + offset),
+            _ => false,
+        })
+        .count()
+}
+
+/// A program that only jumps forwards, and so ends, with one instruction then corrupted so that it
+/// loops. In real programs that is always a `nop` that became a `jmp` back; adversarial ones can
+/// also have a `jmp` forwards that became a `nop`. Programs are drawn again until fixing that one
+/// instruction is the only way to make them end, so there is a single answer.
+fn boot_code(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    let n = size as i64;
+    loop {
+        let mut code: Vec<(&str, i64)> = (0..n)
+            .map(|i| match rng.below(10) {
+                0..=4 => ("acc", rng.range(-50..=50)),
+                5..=6 => ("nop", rng.range(-i.min(20)..=(n - i).min(20))),
+                _ => ("jmp", rng.range(1..=(n - i).min(10))),
+            })
+            .collect();
+        code[0] = ("acc", rng.range(-50..=50));
+
+        let path = executed(&code);
+        if adversarial && rng.chance(0.5) {
+            // jumping past i + 1 ends the program, while falling through to it jumps back
+            let i = *rng.pick(&path[..path.len() - 1]);
+            let back = *rng.pick(&path[..path.iter().position(|p| *p == i).unwrap() + 1]);
+            code[i] = ("nop", rng.range(2..=(n - i as i64).min(10)));
+            code[i + 1] = ("jmp", back as i64 - (i as i64 + 1));
+        } else {
+            // the nop that would jump back to somewhere already run
+            let i = *rng.pick(&path[1..]);
+            let back = *rng.pick(&path[..path.iter().position(|p| *p == i).unwrap() + 1]);
+            let back = if adversarial || back < i { back } else { 0 };
+            code[i] = ("jmp", back as i64 - i as i64);
+        }
+
+        if !ends_from(&code)[0] && fixes(&code) == 1 {
+            return lines(
+                code.into_iter()
+                    .map(|(op, operand)| format!("{} {:+}", op, operand)),
+            );
+        }
+    }
+}
+
+/// Numbers that are each the sum of two of the 25 before them, but one that is the sum of a run
+/// of earlier numbers instead. Each sum takes two of the smallest numbers, so that they grow
+/// slowly enough for the largest size to fit in an `i64`.
+fn xmas(rng: &mut Rng, size: usize, _adversarial: bool) -> String {
+    const PREAMBLE: usize = 25;
+
+    let mut numbers: Vec<i64> = (1..=50).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(PREAMBLE);
+    let invalid_at = rng.range((size / 2).max(PREAMBLE) as i64..=size as i64 - 1) as usize;
+
+    for i in PREAMBLE..size {
+        let mut window: Vec<i64> = numbers[i - PREAMBLE..i].to_vec();
+        window.sort_unstable();
+        window.dedup();
+        let sums: HashSet<i64> = window
+            .iter()
+            .enumerate()
+            .flat_map(|(j, a)| window[j + 1..].iter().map(move |b| a + b))
+            .collect();
+
+        let next = if i == invalid_at {
+            (0..1000)
+                .map(|_| {
+                    let len = rng.range(2..=17.min(i as i64)) as usize;
+                    let start = rng.below(i - len + 1);
+                    numbers[start..start + len].iter().sum::<i64>()
+                })
+                .find(|sum| !sums.contains(sum))
+                .unwrap_or(window[window.len() - 1] * 2 + 1)
+        } else {
+            let smallest = &window[..window.len().min(8)];
+            let a = rng.below(smallest.len());
+            let b = (a + 1 + rng.below(smallest.len() - 1)) % smallest.len();
+            smallest[a] + smallest[b]
+        };
+        numbers.push(next);
+    }
+
+    lines(numbers)
+}
+
+/// Adapters one or three jolts apart, with at most four one jolt apart in a row, as real inputs
+/// have. Adversarial adapters can also be two jolts apart, and in longer runs.
+fn adapters(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    let mut joltages = vec![];
+    let mut joltage = 0;
+    while joltages.len() < size {
+        let run = rng.range(0..=if adversarial { 8 } else { 4 });
+        for _ in 0..run {
+            joltage += 1;
+            joltages.push(joltage);
+        }
+        joltage += if adversarial { rng.range(2..=3) } else { 3 };
+        joltages.push(joltage);
+    }
+    joltages.truncate(size);
+
+    rng.shuffle(&mut joltages);
+    lines(joltages)
+}
+
+/// Whether everyone settles, people getting up when `crowd` of the seats they care about are
+/// taken. Gives up after `rounds` rounds, or as soon as the same seats are taken as two rounds
+/// before, which will keep repeating.
+fn settles(neighbours: &[Vec<usize>], crowd: usize, rounds: usize) -> bool {
+    let mut occupied = vec![false; neighbours.len()];
+    let mut before = vec![];
+    for _ in 0..rounds {
+        let next: Vec<bool> = neighbours
+            .iter()
+            .zip(&occupied)
+            .map(|(seats, taken)| {
+                let around = seats.iter().filter(|s| occupied[**s]).count();
+                if *taken {
+                    around < crowd
+                } else {
+                    around == 0
+                }
+            })
+            .collect();
+        if next == occupied {
+            return true;
+        }
+        if next == before {
+            return false;
+        }
+        before = std::mem::replace(&mut occupied, next);
+    }
+    false
+}
+
+/// A square waiting area, or one of any width when adversarial, where everyone settles under the
+/// rules of both parts, as they do in real ones. Random layouts often don't: patches of seats can
+/// keep emptying and filling up again forever, less often the more floor there is between them,
+/// so layouts are a third floor and drawn again until one settles.
+fn seat_layout(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    // the first seat in each direction, next to the seat or as far as can be seen
+    const DIRECTIONS: [(i64, i64); 8] = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ];
+
+    let width = if adversarial {
+        rng.range(1..=2 * size as i64) as usize
+    } else {
+        size
+    };
+    let rounds = 4 * (size + width);
+    loop {
+        let layout: Vec<Vec<bool>> = (0..size)
+            .map(|_| (0..width).map(|_| rng.chance(0.7)).collect())
+            .collect();
+        let seats: Vec<(usize, usize)> = (0..size)
+            .flat_map(|y| (0..width).map(move |x| (y, x)))
+            .filter(|(y, x)| layout[*y][*x])
+            .collect();
+        let index: HashMap<(usize, usize), usize> =
+            seats.iter().enumerate().map(|(i, s)| (*s, i)).collect();
+        let look = |far: bool| -> Vec<Vec<usize>> {
+            seats
+                .iter()
+                .map(|(y, x)| {
+                    DIRECTIONS
+                        .iter()
+                        .filter_map(|(dy, dx)| {
+                            let (mut y, mut x) = (*y as i64 + dy, *x as i64 + dx);
+                            while (0..size as i64).contains(&y) && (0..width as i64).contains(&x) {
+                                if let Some(seat) = index.get(&(y as usize, x as usize)) {
+                                    return Some(*seat);
+                                }
+                                if !far {
+                                    break;
+                                }
+                                y += dy;
+                                x += dx;
+                            }
+                            None
+                        })
+                        .collect()
+                })
+                .collect()
+        };
+
+        if settles(&look(false), 4, rounds) && settles(&look(true), 5, rounds) {
+            return lines(layout.into_iter().map(|row| {
+                row.into_iter()
+                    .map(|seat| if seat { 'L' } else { '.' })
+                    .collect::<String>()
+            }));
+        }
+    }
+}
+
+/// Navigation instructions, turning by 90, 180 or 270 degrees like real ones, or by any multiple
+/// of 90 degrees when adversarial.
+fn navigation(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    lines((0..size).map(|_| match rng.below(10) {
+        0..=1 => {
+            let turn = rng.pick(&['L', 'R']);
+            let degrees = if adversarial {
+                90 * rng.range(0..=5)
+            } else {
+                *rng.pick(&[90, 90, 90, 180, 270])
+            };
+            format!("{}{}", turn, degrees)
+        }
+        action => format!("{}{}", b"NSEWFFFF"[action - 2] as char, rng.range(1..=100)),
+    }))
+}
+
+/// Buses with prime ids, small enough for the earliest timestamp to fit in an `i64`, leaving
+/// with one in every few minutes. The first bus always leaves first in real notes, and the
+/// timestamp is never one a bus leaves at.
+fn bus_notes(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    let mut limit = 1000;
+    let buses = loop {
+        let mut primes: Vec<u64> = math::primes(limit)
+            .into_iter()
+            .filter(|p| *p >= 7)
+            .collect();
+        rng.shuffle(&mut primes);
+        primes.truncate(size);
+        if primes
+            .iter()
+            .try_fold(1i64, |product, p| product.checked_mul(*p as i64))
+            .is_some_and(|product| product < 1 << 62)
+        {
+            break primes;
+        }
+        limit = (limit * 9 / 10).max(50);
+    };
+
+    let mut slots: Vec<String> = vec!["x".to_string(); size * rng.range(3..=8) as usize];
+    let mut positions: Vec<usize> = (1..slots.len()).collect();
+    rng.shuffle(&mut positions);
+    if !adversarial || rng.chance(0.5) {
+        positions.insert(0, 0);
+    }
+    for (bus, position) in buses.iter().zip(positions) {
+        slots[position] = bus.to_string();
+    }
+
+    let mut timestamp = rng.range(100_000..=1_000_000);
+    if adversarial && rng.chance(0.5) {
+        let bus = *rng.pick(&buses) as i64;
+        timestamp -= timestamp % bus;
+    } else {
+        while buses.iter().any(|bus| timestamp % *bus as i64 == 0) {
+            timestamp += 1;
+        }
+    }
+
+    format!("{}\n{}\n", timestamp, slots.join(","))
+}
+
+/// Masks with up to nine floating bits each, followed by a few writes. Adversarial masks float
+/// up to 16 bits, and the program can write before it sets a mask.
+fn docking_program(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    let mut program = vec![];
+    let write =
+        |rng: &mut Rng| format!("mem[{}] = {}", rng.range(0..=65535), rng.range(0..=1 << 30));
+    if adversarial && rng.chance(0.5) {
+        program.push(write(rng));
+    }
+
+    for _ in 0..size {
+        let floating = rng.range(0..=if adversarial { 16 } else { 9 }) as usize;
+        let mut mask: Vec<char> = (0..36)
+            .map(|i| match i {
+                i if i < floating => 'X',
+                _ => *rng.pick(&['0', '1']),
+            })
+            .collect();
+        rng.shuffle(&mut mask);
+        program.push(format!("mask = {}", mask.into_iter().collect::<String>()));
+        for _ in 0..rng.range(1..=5) {
+            program.push(write(rng));
+        }
+    }
+
+    lines(program)
+}
+
+/// Distinct starting numbers, or ones that can repeat when adversarial.
+fn starting_numbers(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    let max = (3 * size).max(20) as i64;
+    let mut numbers: Vec<i64> = vec![];
+    while numbers.len() < size {
+        let n = rng.range(0..=max);
+        if adversarial || !numbers.contains(&n) {
+            numbers.push(n);
+        }
+    }
+    let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+    numbers.join(",") + "\n"
+}
+
+const TICKET_FIELDS: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+/// Ticket rules of two ranges each with a gap between them, no two gaps overlapping, a quarter of
+/// the tickets with a value no rule allows, and the fields in a shuffled order.
+///
+/// The fields are worked out in a made up order: the first few tickets put a value in the gap of
+/// every later field at each field's position, so that each position rules out the fields after
+/// its own and only one assignment is left. Adversarial tickets have random values only, which
+/// can leave more than one.
+fn ticket_notes(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    let n = TICKET_FIELDS.len();
+    let mut gap_slots: Vec<i64> = (0..n as i64).collect();
+    rng.shuffle(&mut gap_slots);
+    // (lo1, gap, hi2), so that a field allows lo1..gap.start and gap.end + 1..=hi2
+    let rules: Vec<(i64, RangeInclusive<i64>, i64)> = gap_slots
+        .iter()
+        .map(|slot| {
+            let start = 50 + 45 * slot + rng.range(0..=10);
+            let gap = start..=start + rng.range(5..=30);
+            (rng.range(25..=49), gap, rng.range(951..=974))
+        })
+        .collect();
+    let value = |rng: &mut Rng, field: usize| {
+        let (lo1, gap, hi2) = &rules[field];
+        if rng.chance(0.5) {
+            rng.range(*lo1..=gap.start() - 1)
+        } else {
+            rng.range(gap.end() + 1..=*hi2)
+        }
+    };
+
+    let mut order: Vec<usize> = (0..n).collect();
+    rng.shuffle(&mut order);
+    let mut columns: Vec<usize> = (0..n).collect();
+    rng.shuffle(&mut columns);
+
+    let invalid = size / 4;
+    let mut tickets = vec![];
+    for t in 0..size - invalid {
+        let mut ticket = vec![0; n];
+        for (k, field) in order.iter().enumerate() {
+            ticket[columns[*field]] = if !adversarial && t < n - 1 && k < n - 1 {
+                let later = order[k + 1 + t % (n - 1 - k)];
+                rng.range(rules[later].1.clone())
+            } else {
+                value(rng, *field)
+            };
+        }
+        tickets.push(ticket);
+    }
+    for _ in 0..invalid {
+        let mut ticket = tickets[rng.below(tickets.len())].clone();
+        let column = rng.below(n);
+        // a value of 0 adds nothing to the error rate, so real tickets never have one
+        ticket[column] = if rng.chance(0.5) {
+            rng.range(if adversarial { 0 } else { 1 }..=24)
+        } else {
+            rng.range(975..=999)
+        };
+        tickets.push(ticket);
+    }
+    rng.shuffle(&mut tickets);
+
+    let ticket_line = |ticket: &[i64]| {
+        let values: Vec<String> = ticket.iter().map(|v| v.to_string()).collect();
+        values.join(",")
+    };
+    let mut input = String::new();
+    for (name, (lo1, gap, hi2)) in TICKET_FIELDS.iter().zip(&rules) {
+        writeln!(
+            input,
+            "{}: {}-{} or {}-{}",
+            name,
+            lo1,
+            gap.start() - 1,
+            gap.end() + 1,
+            hi2
+        )
+        .unwrap();
+    }
+    let mine: Vec<i64> = (0..n)
+        .map(|column| value(rng, columns.iter().position(|c| *c == column).unwrap()))
+        .collect();
+    writeln!(input, "\nyour ticket:\n{}", ticket_line(&mine)).unwrap();
+    writeln!(input, "\nnearby tickets:").unwrap();
+    for ticket in &tickets {
+        writeln!(input, "{}", ticket_line(ticket)).unwrap();
+    }
+    input
+}
+
+/// A square slice of active and inactive cubes, or one of any width when adversarial.
+fn pocket_slice(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    let width = if adversarial {
+        rng.range(1..=2 * size as i64) as usize
+    } else {
+        size
+    };
+    let mut input = String::new();
+    for _ in 0..size {
+        for _ in 0..width {
+            input.push(if rng.chance(0.5) { '#' } else { '.' });
+        }
+        input.push('\n');
+    }
+    input
+}
+
+enum Rule {
+    Char(char),
+    Alternatives(Vec<Vec<usize>>),
+}
+
+/// A random string that `rule` matches.
+fn expand(rules: &HashMap<usize, Rule>, rule: usize, rng: &mut Rng, out: &mut String) {
+    match &rules[&rule] {
+        Rule::Char(c) => out.push(*c),
+        Rule::Alternatives(alternatives) => {
+            for r in rng.pick(alternatives) {
+                expand(rules, *r, rng, out);
+            }
+        }
+    }
+}
+
+/// A grammar in layers, each rule a choice between pairs of rules of the layer below, with rules
+/// 42 and 31 on top and 0, 8 and 11 as in the puzzle. Real rules 42 and 31 only match strings of
+/// eight letters, while adversarial rules mix one to three rules of any layer below, so that
+/// they match strings of different lengths.
+///
+/// Some messages match rule 0, some only match the looping rules of part 2, and the others are
+/// random letters.
+fn messages(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    const RESERVED: [usize; 5] = [0, 8, 11, 31, 42];
+
+    let mut layers: Vec<Vec<usize>> = vec![vec![0, 1]];
+    let mut alternatives: Vec<Vec<Vec<usize>>> = vec![vec![], vec![]];
+    for layer in 1..=3 {
+        let width = if layer == 3 {
+            2
+        } else {
+            rng.range(2..=5) as usize
+        };
+        let mut rules = vec![];
+        for _ in 0..width {
+            let below: Vec<usize> = if adversarial {
+                layers.iter().flatten().copied().collect()
+            } else {
+                layers[layer - 1].clone()
+            };
+            let rule = (0..rng.range(1..=2))
+                .map(|_| {
+                    let len = if adversarial { rng.range(1..=3) } else { 2 };
+                    (0..len).map(|_| *rng.pick(&below)).collect()
+                })
+                .collect();
+            rules.push(alternatives.len());
+            alternatives.push(rule);
+        }
+        layers.push(rules);
+    }
+
+    // number the rules, with the top two as 42 and 31
+    let mut numbers: Vec<usize> = (1..)
+        .filter(|n| !RESERVED.contains(n))
+        .take(alternatives.len() - 2)
+        .collect();
+    rng.shuffle(&mut numbers);
+    numbers.extend([42, 31]);
+    let mut rules: HashMap<usize, Rule> = HashMap::new();
+    rules.insert(numbers[0], Rule::Char('a'));
+    rules.insert(numbers[1], Rule::Char('b'));
+    for (i, alternatives) in alternatives.into_iter().enumerate().skip(2) {
+        let renumbered = alternatives
+            .into_iter()
+            .map(|seq| seq.into_iter().map(|r| numbers[r]).collect())
+            .collect();
+        rules.insert(numbers[i], Rule::Alternatives(renumbered));
+    }
+    rules.insert(0, Rule::Alternatives(vec![vec![8, 11]]));
+    rules.insert(8, Rule::Alternatives(vec![vec![42]]));
+    rules.insert(11, Rule::Alternatives(vec![vec![42, 31]]));
+
+    let mut grammar: Vec<String> = rules
+        .iter()
+        .map(|(n, rule)| match rule {
+            Rule::Char(c) => format!("{}: \"{}\"", n, c),
+            Rule::Alternatives(alternatives) => {
+                let alternatives: Vec<String> = alternatives
+                    .iter()
+                    .map(|seq| {
+                        let seq: Vec<String> = seq.iter().map(|r| r.to_string()).collect();
+                        seq.join(" ")
+                    })
+                    .collect();
+                format!("{}: {}", n, alternatives.join(" | "))
+            }
+        })
+        .collect();
+    grammar.sort();
+    rng.shuffle(&mut grammar);
+
+    let mut messages = vec![];
+    for _ in 0..size {
+        let mut message = String::new();
+        match rng.below(3) {
+            0 => expand(&rules, 0, rng, &mut message),
+            1 => {
+                // 42 at least once more than 31
+                let elevens = rng.range(1..=3);
+                for _ in 0..elevens + rng.range(1..=3) {
+                    expand(&rules, 42, rng, &mut message);
+                }
+                for _ in 0..elevens {
+                    expand(&rules, 31, rng, &mut message);
+                }
+            }
+            _ => {
+                message = (0..8 * rng.range(2..=6))
+                    .map(|_| *rng.pick(&['a', 'b']))
+                    .collect()
+            }
+        }
+        messages.push(message);
+    }
+
+    format!("{}\n\n{}", grammar.join("\n"), lines(messages))
+}
+
+type Picture = Vec<Vec<char>>;
+
+fn rotate(picture: &Picture) -> Picture {
+    let n = picture.len();
+    (0..n)
+        .map(|row| (0..n).map(|col| picture[n - 1 - col][row]).collect())
+        .collect()
+}
+
+const SEA_MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+/// A square image with sea monsters in it, cut into tiles that share their borders with their
+/// neighbours, and then shuffled, turned and flipped. Every border is different from all the
+/// others, even when flipped, so that only neighbours line up.
+fn image_tiles(rng: &mut Rng, size: usize, _adversarial: bool) -> String {
+    let n = size;
+    let side = 8 * n;
+    let mut image: Picture = (0..side)
+        .map(|_| {
+            (0..side)
+                .map(|_| if rng.chance(0.3) { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    let monster: Vec<(usize, usize)> = SEA_MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.char_indices()
+                .filter(|(_, c)| *c == '#')
+                .map(move |(x, _)| (x, y))
+        })
+        .collect();
+    if side >= SEA_MONSTER[0].len() {
+        let mut taken = HashSet::new();
+        for _ in 0..n * n / 4 {
+            let x = rng.below(side - SEA_MONSTER[0].len() + 1);
+            let y = rng.below(side - SEA_MONSTER.len() + 1);
+            let cells: Vec<(usize, usize)> =
+                monster.iter().map(|(dx, dy)| (x + dx, y + dy)).collect();
+            if cells.iter().all(|cell| !taken.contains(cell)) {
+                for (x, y) in cells {
+                    image[y][x] = '#';
+                    taken.insert((x, y));
+                }
+            }
+        }
+    }
+
+    // the image with a border of one cell around and between the tiles' insides, which are the
+    // tiles' borders
+    let m = 9 * n + 1;
+    let mut sheet: Picture = vec![vec!['.'; m]; m];
+    for (y, row) in image.iter().enumerate() {
+        for (x, c) in row.iter().enumerate() {
+            sheet[9 * (y / 8) + 1 + y % 8][9 * (x / 8) + 1 + x % 8] = *c;
+        }
+    }
+    for y in (0..m).step_by(9) {
+        for x in (0..m).step_by(9) {
+            sheet[y][x] = *rng.pick(&['.', '#']);
+        }
+    }
+    let mut used: HashSet<String> = HashSet::new();
+    let mut border = |rng: &mut Rng, cells: &[(usize, usize)], sheet: &mut Picture| {
+        for _ in 0..10_000 {
+            for (y, x) in &cells[1..9] {
+                sheet[*y][*x] = *rng.pick(&['.', '#']);
+            }
+            let edge: String = cells.iter().map(|(y, x)| sheet[*y][*x]).collect();
+            let flipped: String = edge.chars().rev().collect();
+            if edge != flipped && !used.contains(&edge) && !used.contains(&flipped) {
+                used.insert(edge);
+                break;
+            }
+        }
+    };
+    for row in 0..=n {
+        for col in 0..n {
+            let across: Vec<(usize, usize)> = (0..10).map(|i| (9 * row, 9 * col + i)).collect();
+            border(rng, &across, &mut sheet);
+        }
+    }
+    for row in 0..n {
+        for col in 0..=n {
+            let down: Vec<(usize, usize)> = (0..10).map(|i| (9 * row + i, 9 * col)).collect();
+            border(rng, &down, &mut sheet);
+        }
+    }
+
+    let mut ids: Vec<i64> = (1000..=9999).collect();
+    rng.shuffle(&mut ids);
+    let mut tiles = vec![];
+    for (i, id) in ids.into_iter().take(n * n).enumerate() {
+        let (row, col) = (i / n, i % n);
+        let mut tile: Picture = sheet[9 * row..9 * row + 10]
+            .iter()
+            .map(|line| line[9 * col..9 * col + 10].to_vec())
+            .collect();
+        for _ in 0..rng.below(4) {
+            tile = rotate(&tile);
+        }
+        if rng.chance(0.5) {
+            tile.reverse();
+        }
+        let rows: Vec<String> = tile
+            .into_iter()
+            .map(|row| row.into_iter().collect())
+            .collect();
+        tiles.push(format!("Tile {}:\n{}", id, rows.join("\n")));
+    }
+    rng.shuffle(&mut tiles);
+    tiles.join("\n\n") + "\n"
+}
+
+const ALLERGENS: [&str; 8] = [
+    "dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "soy", "wheat",
+];
+
+/// Foods with their ingredients in a made up language, each allergen in one ingredient. Foods are
+/// added until there is only one way to tell which ingredient has which allergen, except for
+/// adversarial inputs, which can leave more than one.
+fn foods(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    let mut ingredients: HashSet<String> = HashSet::new();
+    while ingredients.len() < 200 {
+        ingredients.insert((0..rng.range(4..=8)).map(|_| rng.letter()).collect());
+    }
+    let mut ingredients: Vec<String> = ingredients.into_iter().collect();
+    ingredients.sort();
+    rng.shuffle(&mut ingredients);
+    let carriers: HashMap<&str, &str> = ALLERGENS
+        .iter()
+        .zip(&ingredients)
+        .map(|(allergen, ingredient)| (*allergen, ingredient.as_str()))
+        .collect();
+
+    let mut foods: Vec<(Vec<&str>, Vec<&str>)> = vec![];
+    let food = |rng: &mut Rng| {
+        let mut food: Vec<&str> = ingredients
+            .iter()
+            .filter(|_| rng.chance(0.3))
+            .map(|i| i.as_str())
+            .collect();
+        let mut allergens: Vec<&str> = ALLERGENS
+            .iter()
+            .filter(|a| food.contains(&carriers[*a]) && rng.chance(0.6))
+            .copied()
+            .collect();
+        if allergens.is_empty() {
+            let allergen = *rng.pick(&ALLERGENS);
+            if !food.contains(&carriers[allergen]) {
+                food.push(carriers[allergen]);
+            }
+            allergens.push(allergen);
+        }
+        rng.shuffle(&mut food);
+        (food, allergens)
+    };
+    for _ in 0..size {
+        foods.push(food(rng));
+    }
+
+    // the ingredients each allergen can be in, as the solution works them out
+    let unique = |foods: &[(Vec<&str>, Vec<&str>)]| {
+        let mut candidates: HashMap<&str, HashSet<&str>> = HashMap::new();
+        for (ingredients, allergens) in foods {
+            let ingredients: HashSet<&str> = ingredients.iter().copied().collect();
+            for allergen in allergens {
+                candidates
+                    .entry(*allergen)
+                    .and_modify(|c| c.retain(|i| ingredients.contains(i)))
+                    .or_insert_with(|| ingredients.clone());
+            }
+        }
+        assign(&candidates).is_ok()
+    };
+    if !adversarial {
+        while !unique(&foods) && foods.len() < 2000 {
+            foods.push(food(rng));
+        }
+    }
+
+    lines(foods.into_iter().map(|(ingredients, allergens)| {
+        format!(
+            "{} (contains {})",
+            ingredients.join(" "),
+            allergens.join(", ")
+        )
+    }))
+}
+
+/// The cards 1 to twice the size dealt into two decks, of different sizes when adversarial.
+fn decks(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    let mut cards: Vec<i64> = (1..=2 * size as i64).collect();
+    rng.shuffle(&mut cards);
+    let split = if adversarial {
+        1 + rng.below(2 * size - 1)
+    } else {
+        size
+    };
+    format!(
+        "Player 1:\n{}\nPlayer 2:\n{}",
+        lines(&cards[..split]),
+        lines(&cards[split..])
+    )
+}
+
+fn cup_labels(rng: &mut Rng, _size: usize, _adversarial: bool) -> String {
+    let mut labels: Vec<char> = ('1'..='9').collect();
+    rng.shuffle(&mut labels);
+    labels.into_iter().collect::<String>() + "\n"
+}
+
+/// Paths of 15 to 25 steps, or of up to 200 when adversarial, which spreads the tiles far out.
+fn tile_paths(rng: &mut Rng, size: usize, adversarial: bool) -> String {
+    const DIRECTIONS: [&str; 6] = ["e", "se", "sw", "w", "nw", "ne"];
+    let longest = if adversarial { 200 } else { 25 };
+    lines((0..size).map(|_| {
+        (0..rng.range(15..=longest))
+            .map(|_| *rng.pick(&DIRECTIONS))
+            .collect::<String>()
+    }))
+}
+
+/// The public keys of a card and a door whose loop sizes are at most the size.
+fn public_keys(rng: &mut Rng, size: usize, _adversarial: bool) -> String {
+    const MODULUS: i64 = 20201227;
+    let mut key = || math::powmod(7, rng.range(1..=size as i64) as u64, MODULUS);
+    format!("{}\n{}\n", key(), key())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+    use aoc_common::Answer;
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(42);
+        let first: Vec<u64> = (0..3).map(|_| rng.next_u64()).collect();
+        let mut again = Rng::new(42);
+        assert_eq!(first, (0..3).map(|_| again.next_u64()).collect::<Vec<_>>());
+        // the first output of SplitMix64 seeded with 0
+        assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+
+        assert!((0..1000).all(|_| rng.below(7) < 7));
+        assert!((0..1000).all(|_| (-3..=3).contains(&rng.range(-3..=3))));
+        let mut items: Vec<u32> = (0..10).collect();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_every_day() {
+        for day in registry::DAYS {
+            let generator = find(day.day).unwrap();
+            let sizes = match &generator.size {
+                Some(size) => vec![Some(*size.range.start()), Some(size.default)],
+                None => vec![None],
+            };
+            for size in sizes {
+                for adversarial in [false, true] {
+                    let input = generator.generate(7, size, adversarial).unwrap();
+                    assert_eq!(generator.generate(7, size, adversarial).unwrap(), input);
+                    assert!(
+                        day.solver.parse(&input).is_ok(),
+                        "day {} can't parse {:?}",
+                        day.day,
+                        input
+                    );
+                }
+            }
+
+            // and the day gets both answers out of an input the size of a real one
+            let input = generator.generate(7, None, false).unwrap();
+            let parsed = day.solver.parse(&input).unwrap();
+            for part in 1..=2 {
+                let answer =
+                    panic::catch_unwind(AssertUnwindSafe(|| day.solver.part(&parsed, part)));
+                assert!(answer.is_ok(), "day {} part {} panicked", day.day, part);
+                if (day.day, part) != (25, 2) {
+                    assert_ne!(
+                        answer.unwrap(),
+                        Answer::Empty,
+                        "day {} part {}",
+                        day.day,
+                        part
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_size() {
+        let generator = find(13).unwrap();
+        assert_ne!(
+            generator.generate(1, None, false),
+            generator.generate(2, None, false)
+        );
+        assert_eq!(
+            generator.generate(1, Some(13), false),
+            Err("Day 13's size is the number of buses, between 1 and 12".to_string())
+        );
+        assert_eq!(
+            find(23).unwrap().generate(1, Some(9), false),
+            Err("Day 23's input has a fixed size".to_string())
+        );
+        let input = find(9).unwrap().generate(3, Some(1200), false).unwrap();
+        assert_eq!(input.lines().count(), 1200);
+    }
+
+    /// How many of the programs with one `jmp` or `nop` swapped end, trying each one.
+    fn boot_code_fixes(input: &str) -> usize {
+        let code: Vec<(&str, i64)> = input
+            .lines()
+            .map(|line| {
+                let (op, operand) = line.split_once(' ').unwrap();
+                (op, operand.parse().unwrap())
+            })
+            .collect();
+        (0..code.len())
+            .filter(|&i| {
+                let mut fixed = code.clone();
+                fixed[i].0 = match code[i].0 {
+                    "jmp" => "nop",
+                    "nop" => "jmp",
+                    _ => return false,
+                };
+                let last = *executed(&fixed).last().unwrap();
+                let next = match fixed[last] {
+                    ("jmp", offset) => last as i64 + offset,
+                    _ => last as i64 + 1,
+                };
+                !(0..code.len() as i64).contains(&next)
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_solvable() {
+        // the days that rely on the input having exactly one answer, on inputs small enough to
+        // solve quickly in a debug build
+        for (day, size) in [
+            (1, 50),
+            (8, 200),
+            (9, 200),
+            (10, 60),
+            (16, 100),
+            (20, 3),
+            (21, 20),
+        ] {
+            let solver = registry::find(day).unwrap().solver;
+            for seed in 0..5 {
+                let input = find(day)
+                    .unwrap()
+                    .generate(seed, Some(size), false)
+                    .unwrap();
+                let parsed = solver.parse(&input).unwrap();
+                solver.part(&parsed, 1);
+                solver.part(&parsed, 2);
+            }
+        }
+
+        // day 9 inputs with the invalid number last, the second only 26 numbers long
+        let solver = registry::find(9).unwrap().solver;
+        for (seed, size) in [(33, None), (0, Some(26))] {
+            let input = find(9).unwrap().generate(seed, size, false).unwrap();
+            let parsed = solver.parse(&input).unwrap();
+            solver.part(&parsed, 1);
+            solver.part(&parsed, 2);
+        }
+
+        // day 8's answer is the first fix that ends, so it has to be the only one
+        let generator = find(8).unwrap();
+        for seed in 0..40 {
+            for adversarial in [false, true] {
+                let input = generator.generate(seed, Some(200), adversarial).unwrap();
+                assert_eq!(boot_code_fixes(&input), 1, "seed {}", seed);
+            }
+        }
+    }
+}
@@ -0,0 +1,10 @@
+pub mod alloc;
+pub mod answers;
+pub mod config;
+pub mod generate;
+pub mod registry;
+pub mod repl;
+pub mod report;
+pub mod scaffold;
+pub mod serve;
+pub mod watch;
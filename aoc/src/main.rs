@@ -0,0 +1,703 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, IsTerminal, Write},
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+    process, thread,
+    time::{Duration, Instant},
+};
+
+use aoc_common::{
+    render::{self, Pace},
+    Answer, ParseError, Parsed,
+};
+use clap::{ArgAction, Parser, Subcommand, ValueEnum};
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
+
+use aoc::{
+    alloc,
+    answers::Answers,
+    config::{self, Config, ParamArg, Source},
+    generate,
+    registry::{self, Day},
+    repl, report, scaffold, serve, watch,
+};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2020 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Config file [default: aoc.toml, if it exists]
+    #[arg(long, global = true, env = "AOC_CONFIG")]
+    config: Option<PathBuf>,
+    /// Directory with the dayN.txt inputs [default: input/2020]
+    #[arg(long, global = true, env = "AOC_INPUT_DIR")]
+    input_dir: Option<PathBuf>,
+    /// Set a puzzle parameter, e.g. `9.preamble=5`
+    #[arg(long = "param", global = true, value_parser = config::parse_param)]
+    params: Vec<ParamArg>,
+    /// Log the days' debug output to stderr, and their trace output with -vv
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, a range of days or the whole calendar
+    Run {
+        /// A day (`7`), a range of days (`1-5`) or `all`
+        #[arg(value_parser = parse_days)]
+        days: RangeInclusive<u32>,
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+        /// Read the input from this file, or from stdin for `-`, instead of the input directory
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<PathBuf>,
+        /// Say what parsing and each part allocated, on stderr; needs `--features alloc-stats`
+        #[arg(long)]
+        memory: bool,
+    },
+    /// Check the answers on the real input against the recorded ones
+    Verify {
+        /// A day (`7`), a range of days (`1-5`) or `all`
+        #[arg(value_parser = parse_days, default_value = "all")]
+        days: RangeInclusive<u32>,
+    },
+    /// Run the days side by side and tabulate their answers, timings and verification
+    Report {
+        /// A day (`7`), a range of days (`1-5`) or `all`
+        #[arg(value_parser = parse_days, default_value = "all")]
+        days: RangeInclusive<u32>,
+        /// How many days to run at once [default: the number of cores]
+        #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+        jobs: Option<u32>,
+        #[arg(long, value_enum, default_value_t = Format::Table)]
+        format: Format,
+    },
+    /// Record the current answers on the real input as the verified ones
+    Record {
+        /// A day (`7`), a range of days (`1-5`) or `all`
+        #[arg(value_parser = parse_days)]
+        days: RangeInclusive<u32>,
+        /// Only record this part
+        #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: Option<u32>,
+    },
+    /// Show how the simulation of a day evolves, frame by frame
+    Animate {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        /// The part whose simulation to show
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..=2))]
+        part: u32,
+        /// Read the input from this file, or from stdin for `-`, instead of the input directory
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<PathBuf>,
+        /// Frames per second
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..=1000))]
+        fps: u32,
+        /// Wait for Enter after each frame instead
+        #[arg(long, conflicts_with = "fps")]
+        step: bool,
+        /// Write the frames as plain text to this file, or to stdout for `-`, instead of playing them
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["fps", "step"])]
+        dump: Option<PathBuf>,
+    },
+    /// Write a random input for a day, the same one every time for the same seed and size
+    Generate {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How big to make the input, in the day's own terms [default: the size of a real input]
+        #[arg(long)]
+        size: Option<usize>,
+        /// Only keep to what the puzzle allows, not to the habits of the real inputs
+        #[arg(long)]
+        adversarial: bool,
+        /// Write the input to this file instead of stdout
+        #[arg(short, long, value_name = "PATH")]
+        output: Option<PathBuf>,
+    },
+    /// Load a day's input once and run its parts and commands on it interactively
+    Repl {
+        /// The day to start with; `day N` loads another one
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: Option<u32>,
+        /// Read the input from this file instead of the input directory
+        #[arg(short, long, value_name = "PATH", requires = "day")]
+        input: Option<PathBuf>,
+    },
+    /// Test and run a day again whenever its code, examples or input change
+    Watch {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+    },
+    /// Answer POST /solve requests with the solvers, over HTTP
+    Serve {
+        /// The address to listen on
+        #[arg(long, default_value = "127.0.0.1:2020")]
+        addr: String,
+    },
+    /// Start a new day from template.rs and register it with the workspace and the runner
+    New {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+        /// The calendar the day belongs to
+        #[arg(long, default_value_t = scaffold::YEAR)]
+        year: u32,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Table,
+    Json,
+    Markdown,
+}
+
+fn parse_days(input: &str) -> Result<RangeInclusive<u32>, String> {
+    let parse = |s: &str| {
+        s.trim()
+            .parse::<u32>()
+            .ok()
+            .filter(|d| (1..=25).contains(d))
+            .ok_or_else(|| format!("'{}' is not a day between 1 and 25", s))
+    };
+
+    if input == "all" {
+        return Ok(1..=25);
+    }
+    match input.split_once('-') {
+        Some((from, to)) => {
+            let (from, to) = (parse(from)?, parse(to)?);
+            if from > to {
+                return Err(format!("'{}' is an empty range", input));
+            }
+            Ok(from..=to)
+        }
+        None => parse(input).map(|d| d..=d),
+    }
+}
+
+fn parts(part: Option<u32>) -> RangeInclusive<u32> {
+    match part {
+        Some(p) => p..=p,
+        None => 1..=2,
+    }
+}
+
+fn parse_input(
+    config: &Config,
+    solvers: &Day,
+    input: &str,
+    source: &Source,
+) -> Result<Parsed, ParseError> {
+    solvers
+        .solver
+        .parse_with(input, &config.params(solvers.day))
+        .map_err(|err| source.locate(err))
+}
+
+fn run(
+    config: &Config,
+    days: RangeInclusive<u32>,
+    part: Option<u32>,
+    input: Option<PathBuf>,
+    memory: bool,
+) -> bool {
+    if input.is_some() && days.start() != days.end() {
+        eprintln!("--input can only be used with a single day");
+        return false;
+    }
+    if memory && !alloc::ENABLED {
+        eprintln!("--memory needs a build with the counting allocator: --features alloc-stats");
+        return false;
+    }
+    let report = |what: String, stats: Option<alloc::Stats>| {
+        if let (true, Some(stats)) = (memory, stats) {
+            eprintln!("{}: {}", what, stats);
+        }
+    };
+    let mut ok = true;
+
+    for day in days.clone() {
+        let solvers = match registry::find(day) {
+            Some(solvers) => solvers,
+            None => {
+                // only complain when asked for this day specifically
+                if days.start() == days.end() {
+                    eprintln!("Day {} has no Rust solution", day);
+                    ok = false;
+                }
+                continue;
+            }
+        };
+
+        let source = match &input {
+            Some(path) => Source::from_arg(path),
+            None => config.source(day),
+        };
+        let input = match source.read() {
+            Some(input) => input,
+            None => {
+                if let Source::File(filename) = &source {
+                    eprintln!("Day {}: no input at {}", day, filename.display());
+                }
+                ok = false;
+                continue;
+            }
+        };
+
+        let (parsed, stats) = alloc::measure(|| parse_input(config, solvers, &input, &source));
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("Day {}: {}", day, err);
+                ok = false;
+                continue;
+            }
+        };
+        report(format!("Day {} parse memory", day), stats);
+        for p in parts(part) {
+            let (answer, stats) = alloc::measure(|| solvers.solver.part(&parsed, p));
+            if answer != Answer::Empty {
+                println!("Day {} part {}: {}", day, p, answer);
+                report(format!("Day {} part {} memory", day, p), stats);
+            }
+        }
+    }
+
+    ok
+}
+
+/// Runs every day with recorded answers and reports those that no longer match.
+fn verify(config: &Config, days: RangeInclusive<u32>) -> bool {
+    let answers = match Answers::load(&config.answers) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err);
+            return false;
+        }
+    };
+    let (mut checked, mut wrong) = (0, 0);
+    let mut ok = true;
+
+    for day in days {
+        let solvers = match registry::find(day) {
+            Some(solvers) => solvers,
+            None => continue,
+        };
+        let expected: Vec<(u32, &str)> = (1..=2)
+            .filter_map(|p| answers.get(day, p).map(|answer| (p, answer)))
+            .collect();
+        if expected.is_empty() {
+            continue;
+        }
+
+        let source = config.source(day);
+        let input = match source.read() {
+            Some(input) => input,
+            None => {
+                // inputs are personal and not checked in, so this is not a failure
+                if let Source::File(filename) = &source {
+                    eprintln!("Day {}: skipped, no input at {}", day, filename.display());
+                }
+                continue;
+            }
+        };
+        let parsed = match parse_input(config, solvers, &input, &source) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("Day {}: {}", day, err);
+                ok = false;
+                continue;
+            }
+        };
+
+        for (p, expected) in expected {
+            let answer = solvers.solver.part(&parsed, p).to_string();
+            checked += 1;
+            if answer == expected {
+                println!("Day {} part {}: ok", day, p);
+            } else {
+                println!(
+                    "Day {} part {}: expected {}, got {}",
+                    day, p, expected, answer
+                );
+                wrong += 1;
+            }
+        }
+    }
+
+    println!("{} answers checked, {} wrong", checked, wrong);
+    ok && wrong == 0
+}
+
+/// Runs the days `jobs` at a time and prints how they did. Fails when any answer is wrong or a
+/// day could not be solved.
+fn report(config: &Config, days: RangeInclusive<u32>, jobs: Option<u32>, format: Format) -> bool {
+    let answers = match Answers::load(&config.answers) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err);
+            return false;
+        }
+    };
+    let jobs = jobs.map_or_else(
+        || thread::available_parallelism().map_or(4, |n| n.get()),
+        |jobs| jobs as usize,
+    );
+
+    let start = Instant::now();
+    let rows = match report::run(config, &answers, days, jobs) {
+        Ok(rows) => rows,
+        Err(err) => {
+            eprintln!("{}", err);
+            return false;
+        }
+    };
+    let output = match format {
+        Format::Table => report::table(&rows),
+        Format::Json => report::json(&rows),
+        Format::Markdown => report::markdown(&rows),
+    };
+    print!("{}", output);
+    // on stderr, so that the JSON and Markdown can be piped as they are
+    eprintln!(
+        "{} rows in {:.3} ms on {} thread{}",
+        rows.len(),
+        start.elapsed().as_secs_f64() * 1000.0,
+        jobs,
+        if jobs == 1 { "" } else { "s" }
+    );
+    report::passed(&rows)
+}
+
+/// Stores the answers on the real input in the answers file, replacing any recorded before.
+fn record(config: &Config, days: RangeInclusive<u32>, part: Option<u32>) -> bool {
+    let mut answers = match Answers::load(&config.answers) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err);
+            return false;
+        }
+    };
+    let mut ok = true;
+
+    for day in days.clone() {
+        let solvers = match registry::find(day) {
+            Some(solvers) => solvers,
+            None => continue,
+        };
+        let source = config.source(day);
+        let input = match source.read() {
+            Some(input) => input,
+            None => {
+                if let Source::File(filename) = &source {
+                    eprintln!("Day {}: no input at {}", day, filename.display());
+                }
+                ok = false;
+                continue;
+            }
+        };
+        let parsed = match parse_input(config, solvers, &input, &source) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                eprintln!("Day {}: {}", day, err);
+                ok = false;
+                continue;
+            }
+        };
+
+        for p in parts(part) {
+            let answer = solvers.solver.part(&parsed, p);
+            if answer != Answer::Empty {
+                println!("Day {} part {}: {}", day, p, answer);
+                answers.set(day, p, answer.to_string());
+            }
+        }
+    }
+
+    if let Err(err) = answers.save(&config.answers) {
+        eprintln!("{}", err);
+        return false;
+    }
+    ok
+}
+
+struct Playback {
+    pace: Pace,
+    dump: Option<PathBuf>,
+}
+
+/// Plays the animation of one part of a day, or dumps its frames.
+fn animate(
+    config: &Config,
+    day: u32,
+    part: u32,
+    input: Option<PathBuf>,
+    playback: Playback,
+) -> bool {
+    let solvers = match registry::find(day) {
+        Some(solvers) => solvers,
+        None => {
+            eprintln!("Day {} has no Rust solution", day);
+            return false;
+        }
+    };
+    let source = match &input {
+        Some(path) => Source::from_arg(path),
+        None => config.source(day),
+    };
+    if source == Source::Stdin && playback.pace == Pace::Step && playback.dump.is_none() {
+        eprintln!("--step reads keys from stdin, so the input can't come from there too");
+        return false;
+    }
+    let input = match source.read() {
+        Some(input) => input,
+        None => {
+            if let Source::File(filename) = &source {
+                eprintln!("Day {}: no input at {}", day, filename.display());
+            }
+            return false;
+        }
+    };
+    let parsed = match parse_input(config, solvers, &input, &source) {
+        Ok(parsed) => parsed,
+        Err(err) => {
+            eprintln!("Day {}: {}", day, err);
+            return false;
+        }
+    };
+    let frames = match solvers.solver.animate(&parsed, part) {
+        Some(frames) => frames,
+        None => {
+            eprintln!("Day {} part {} has no animation", day, part);
+            return false;
+        }
+    };
+
+    let result = match playback.dump {
+        Some(path) if path == Path::new("-") => render::dump(frames, &mut io::stdout().lock()),
+        Some(path) => File::create(&path)
+            .and_then(|file| render::dump(frames, &mut BufWriter::new(file)))
+            .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err))),
+        None => render::play(
+            frames,
+            playback.pace,
+            &mut io::stdout().lock(),
+            &mut io::stdin().lock(),
+        ),
+    };
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        return false;
+    }
+    true
+}
+
+struct Knobs {
+    seed: u64,
+    size: Option<usize>,
+    adversarial: bool,
+}
+
+fn generate(day: u32, knobs: Knobs, output: Option<PathBuf>) -> bool {
+    let generator = match generate::find(day) {
+        Some(generator) => generator,
+        None => {
+            eprintln!("Day {} has no input generator", day);
+            return false;
+        }
+    };
+    let input = match generator.generate(knobs.seed, knobs.size, knobs.adversarial) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err);
+            return false;
+        }
+    };
+
+    let result = match output {
+        Some(path) => fs::write(&path, input).map_err(|err| format!("{}: {}", path.display(), err)),
+        None => io::stdout()
+            .lock()
+            .write_all(input.as_bytes())
+            .map_err(|err| err.to_string()),
+    };
+    if let Err(err) = result {
+        eprintln!("{}", err);
+        return false;
+    }
+    true
+}
+
+fn new_day(config: &Config, day: u32, year: u32) -> bool {
+    let dir = match scaffold::new_day(Path::new("."), day, year) {
+        Ok(dir) => dir,
+        Err(err) => {
+            eprintln!("{}", err);
+            return false;
+        }
+    };
+
+    println!("Created {}", dir.display());
+    println!(
+        "The input goes in {}",
+        config.input_dir.join(format!("day{}.txt", day)).display()
+    );
+    if config.input_dir != Path::new("input").join(year.to_string()) {
+        println!(
+            "Set input_dir = \"input/{}\" in {} to read the {} inputs",
+            year,
+            config::DEFAULT_CONFIG,
+            year
+        );
+    }
+    true
+}
+
+/// Sends the days' logs to stderr, keeping stdout for the answers. `RUST_LOG` can pick out days
+/// and levels, like `RUST_LOG=day22=trace`; everything else logs at the level `-v` sets.
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => LevelFilter::WARN,
+        1 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+    let filter = EnvFilter::builder()
+        .with_default_directive(level.into())
+        .from_env_lossy();
+    // with -v, also say how long parsing and each part took
+    let spans = if verbose > 0 {
+        FmtSpan::CLOSE
+    } else {
+        FmtSpan::NONE
+    };
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(spans)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .init();
+}
+
+fn main() {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+
+    let config = match Config::load(cli.config.as_deref(), cli.input_dir, &cli.params) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    };
+
+    let ok = match cli.command {
+        Command::Run {
+            days,
+            part,
+            input,
+            memory,
+        } => run(&config, days, part, input, memory),
+        Command::Verify { days } => verify(&config, days),
+        Command::Report { days, jobs, format } => report(&config, days, jobs, format),
+        Command::Record { days, part } => record(&config, days, part),
+        Command::Animate {
+            day,
+            part,
+            input,
+            fps,
+            step,
+            dump,
+        } => {
+            let pace = if step {
+                Pace::Step
+            } else {
+                Pace::Delay(Duration::from_secs(1) / fps)
+            };
+            animate(&config, day, part, input, Playback { pace, dump })
+        }
+        Command::Generate {
+            day,
+            seed,
+            size,
+            adversarial,
+            output,
+        } => {
+            let knobs = Knobs {
+                seed,
+                size,
+                adversarial,
+            };
+            generate(day, knobs, output)
+        }
+        Command::Repl { day, input } => {
+            let stdin = io::stdin();
+            let prompt = stdin.is_terminal();
+            let day = day.map(|day| (day, input));
+            match repl::repl(&config, day, stdin.lock(), &mut io::stdout(), prompt) {
+                Ok(()) => true,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    false
+                }
+            }
+        }
+        Command::Watch { day } => {
+            // the runs happen in new processes, which need the same settings
+            let mut forward = vec![];
+            if let Some(path) = &cli.config {
+                forward.push(format!("--config={}", path.display()));
+            }
+            forward.push(format!("--input-dir={}", config.input_dir.display()));
+            for param in &cli.params {
+                forward.push(format!(
+                    "--param={}.{}={}",
+                    param.day, param.key, param.value
+                ));
+            }
+            match watch::watch(&config, day, &forward) {
+                Ok(()) => true,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    false
+                }
+            }
+        }
+        Command::Serve { addr } => match serve::serve(&config, &addr) {
+            Ok(()) => true,
+            Err(err) => {
+                eprintln!("{}", err);
+                false
+            }
+        },
+        Command::New { day, year } => new_day(&config, day, year),
+    };
+
+    if !ok {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("7"), Ok(7..=7));
+        assert_eq!(parse_days("1-5"), Ok(1..=5));
+        assert_eq!(parse_days("all"), Ok(1..=25));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("5-1").is_err());
+        assert!(parse_days("seven").is_err());
+    }
+}
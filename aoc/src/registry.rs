@@ -0,0 +1,89 @@
+use aoc_common::Solver;
+
+pub struct Day {
+    pub day: u32,
+    pub solver: Solver,
+}
+
+macro_rules! day {
+    ($day:expr, $krate:ident :: $solution:ident) => {
+        Day {
+            day: $day,
+            solver: Solver::new::<$krate::$solution>(),
+        }
+    };
+}
+
+// day18 is solved in Python, see day18/part1.py and day18/part2.py
+pub static DAYS: &[Day] = &[
+    day!(1, day1::Day1),
+    day!(2, day2::Day2),
+    day!(3, day3::Day3),
+    day!(4, day4::Day4),
+    day!(5, day5::Day5),
+    day!(6, day6::Day6),
+    day!(7, day7::Day7),
+    day!(8, day8::Day8),
+    day!(9, day9::Day9),
+    day!(10, day10::Day10),
+    day!(11, day11::Day11),
+    day!(12, day12::Day12),
+    day!(13, day13::Day13),
+    day!(14, day14::Day14),
+    day!(15, day15::Day15),
+    day!(16, day16::Day16),
+    day!(17, day17::Day17),
+    day!(19, day19::Day19),
+    day!(20, day20::Day20),
+    day!(21, day21::Day21),
+    day!(22, day22::Day22),
+    day!(23, day23::Day23),
+    day!(24, day24::Day24),
+    day!(25, day25::Day25),
+];
+
+pub fn find(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// What a solver panicked with, for the ones that give up on input they don't expect.
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("no message")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{string_from_file, Answer};
+
+    #[test]
+    fn test_find() {
+        assert_eq!(find(7).unwrap().day, 7);
+        assert!(find(18).is_none());
+        assert!(find(26).is_none());
+    }
+
+    #[test]
+    fn test_example() {
+        let solver = find(1).unwrap().solver;
+        let parsed = solver
+            .parse(&string_from_file("../day1/example.txt"))
+            .unwrap();
+        assert_eq!(solver.part(&parsed, 1), 514579);
+
+        let solver = find(21).unwrap().solver;
+        let parsed = solver
+            .parse(&string_from_file("../day21/example.txt"))
+            .unwrap();
+        assert_eq!(solver.part(&parsed, 2), "mxmxvkd,sqjhc,fvjkl");
+
+        let solver = find(25).unwrap().solver;
+        let parsed = solver.parse("5764801\n17807724").unwrap();
+        assert_eq!(solver.part(&parsed, 1), 14897079);
+        assert_eq!(solver.part(&parsed, 2), Answer::Empty);
+    }
+}
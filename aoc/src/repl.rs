@@ -0,0 +1,279 @@
+//! `aoc repl`: loads a day's input once and answers questions about it, without parsing it again
+//! or rebuilding for every one. Besides solving the parts, days can offer their own commands by
+//! implementing `Solution::explore`.
+
+use std::{
+    io::{self, BufRead, Write},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use aoc_common::{Answer, Parsed};
+
+use crate::{
+    config::{Config, Source},
+    registry::{self, panic_message, Day},
+};
+
+const COMMANDS: &[(&str, &str)] = &[
+    (
+        "day <N> [PATH]",
+        "load the input of day N, from PATH instead of the input directory",
+    ),
+    ("part <1|2>", "solve a part of the loaded day"),
+    ("help", "list the commands"),
+    ("quit", "leave, as does end of input"),
+];
+
+/// A day with its parsed input.
+struct Loaded {
+    day: &'static Day,
+    parsed: Parsed,
+}
+
+enum Next {
+    Load(u32, Option<PathBuf>),
+    Quit,
+}
+
+fn load(config: &Config, day: u32, path: Option<&Path>) -> Result<Loaded, String> {
+    let solvers = registry::find(day).ok_or_else(|| format!("Day {} has no Rust solution", day))?;
+    let source = match path {
+        Some(path) => Source::from_arg(path),
+        None => config.source(day),
+    };
+    let input = match &source {
+        Source::Stdin => {
+            return Err(
+                "The commands come from stdin, so the input can't come from there too".into(),
+            )
+        }
+        Source::File(filename) => source
+            .read()
+            .ok_or_else(|| format!("Day {}: no input at {}", day, filename.display()))?,
+        Source::Inline(_) => source.read().unwrap_or_default(),
+    };
+
+    let parsed = solvers
+        .solver
+        .parse_with(&input, &config.params(day))
+        .map_err(|err| format!("Day {}: {}", day, source.locate(err)))?;
+    Ok(Loaded {
+        day: solvers,
+        parsed,
+    })
+}
+
+fn help(out: &mut impl Write, commands: &[(&str, &str)]) -> io::Result<()> {
+    let width = commands
+        .iter()
+        .map(|(usage, _)| usage.len())
+        .max()
+        .unwrap_or(0);
+    for (usage, what) in commands {
+        writeln!(out, "  {:width$}  {}", usage, what, width = width)?;
+    }
+    Ok(())
+}
+
+/// Runs `f`, turning a panic into an error so that one bad command doesn't end the session.
+fn guard<T>(what: impl FnOnce() -> String, f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .map_err(|payload| format!("{} panicked: {}", what(), panic_message(&*payload)))
+}
+
+/// Takes commands until one loads another day or there are none left.
+fn session(
+    loaded: Option<&Loaded>,
+    lines: &mut impl Iterator<Item = io::Result<String>>,
+    out: &mut impl Write,
+    prompt: bool,
+) -> io::Result<Next> {
+    let mut explorer = loaded.and_then(|loaded| loaded.day.solver.explore(&loaded.parsed));
+
+    loop {
+        if prompt {
+            match loaded {
+                Some(loaded) => write!(out, "day{}> ", loaded.day.day)?,
+                None => write!(out, "aoc> ")?,
+            }
+            out.flush()?;
+        }
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => return Ok(Next::Quit),
+        };
+        let line = line.trim();
+        let (command, args) = line.split_once(' ').unwrap_or((line, ""));
+
+        let result: Result<String, String> = match (command, loaded) {
+            ("", _) => continue,
+            ("quit", _) | ("exit", _) => return Ok(Next::Quit),
+            ("help", _) => {
+                let mut commands = COMMANDS.to_vec();
+                if let Some(explorer) = &explorer {
+                    commands.extend(explorer.commands());
+                }
+                help(out, &commands)?;
+                continue;
+            }
+            ("day", _) => {
+                let mut args = args.split_whitespace();
+                match args.next().map(str::parse) {
+                    Some(Ok(day)) => return Ok(Next::Load(day, args.next().map(PathBuf::from))),
+                    _ => Err("Which day? Like 'day 7'".to_string()),
+                }
+            }
+            (_, None) => Err("Load a day first, like 'day 7'".to_string()),
+            ("part", Some(loaded)) => match args.trim().parse() {
+                Ok(part @ 1..=2) => {
+                    let start = Instant::now();
+                    guard(
+                        || format!("Day {} part {}", loaded.day.day, part),
+                        || loaded.day.solver.part(&loaded.parsed, part),
+                    )
+                    .map(|answer| {
+                        let ms = start.elapsed().as_secs_f64() * 1000.0;
+                        match answer {
+                            Answer::Empty => format!("Part {} has no puzzle", part),
+                            answer => format!("Part {}: {} ({:.3} ms)", part, answer, ms),
+                        }
+                    })
+                }
+                _ => Err("Which part? 'part 1' or 'part 2'".to_string()),
+            },
+            (_, Some(loaded)) => {
+                let ran = explorer.as_mut().map(|explorer| {
+                    guard(
+                        || format!("Day {} {}", loaded.day.day, command),
+                        || explorer.run(command, args),
+                    )
+                });
+                match ran {
+                    Some(Ok(Some(result))) => result,
+                    Some(Err(err)) => Err(err),
+                    _ => Err(format!("Unknown command '{}', try 'help'", command)),
+                }
+            }
+        };
+
+        match result {
+            Ok(shown) => writeln!(out, "{}", shown)?,
+            Err(err) => writeln!(out, "{}", err)?,
+        }
+    }
+}
+
+/// Reads commands from `input` and writes what they show to `out`, starting with `day` loaded
+/// when given. `prompt` is for when someone is typing them.
+pub fn repl(
+    config: &Config,
+    day: Option<(u32, Option<PathBuf>)>,
+    input: impl BufRead,
+    out: &mut impl Write,
+    prompt: bool,
+) -> io::Result<()> {
+    let mut lines = input.lines();
+    let mut next = match day {
+        Some((day, path)) => Next::Load(day, path),
+        None => session(None, &mut lines, out, prompt)?,
+    };
+
+    while let Next::Load(day, path) = next {
+        let start = Instant::now();
+        next = match load(config, day, path.as_deref()) {
+            Ok(loaded) => {
+                let ms = start.elapsed().as_secs_f64() * 1000.0;
+                writeln!(out, "Loaded day {} ({:.3} ms)", day, ms)?;
+                session(Some(&loaded), &mut lines, out, prompt)?
+            }
+            Err(err) => {
+                writeln!(out, "{}", err)?;
+                session(None, &mut lines, out, prompt)?
+            }
+        };
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn run(day: Option<(u32, Option<PathBuf>)>, commands: &str) -> String {
+        let config = Config::load(None, None, &[]).unwrap();
+        let mut out = vec![];
+        repl(&config, day, commands.as_bytes(), &mut out, false).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    /// Drops the timings, which change from run to run.
+    fn untimed(output: &str) -> Vec<&str> {
+        output
+            .lines()
+            .map(|line| line.split(" (").next().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_repl() {
+        let day7 = Some((7, Some(PathBuf::from("../day7/example.txt"))));
+        let output = run(
+            day7,
+            "part 1\npart 2\ncontains shiny gold\nfly\n\npart 3\nquit\npart 1",
+        );
+        assert_eq!(
+            untimed(&output),
+            vec![
+                "Loaded day 7",
+                "Part 1: 4",
+                "Part 2: 32",
+                "4 bags: bright white, dark orange, light red, muted yellow",
+                "Unknown command 'fly', try 'help'",
+                "Which part? 'part 1' or 'part 2'",
+            ]
+        );
+
+        let output = run(None, "part 1\nday 8 ../day8/example.txt\nstep 2\nhelp");
+        let lines = untimed(&output);
+        assert_eq!(
+            lines[..4],
+            [
+                "Load a day first, like 'day 7'",
+                "Loaded day 8",
+                "0: nop +0 -> acc 0, pc 1",
+                "1: acc +1 -> acc 1, pc 2"
+            ]
+        );
+        assert!(lines.iter().any(|line| line.starts_with("  part <1|2>")));
+        assert!(lines.iter().any(|line| line.starts_with("  step [N]")));
+    }
+
+    #[test]
+    fn test_errors() {
+        let output = run(
+            None,
+            "day 18\nday\nday 7 nowhere.txt\nday 25 -\nday 7 ../day8/example.txt",
+        );
+        assert_eq!(
+            output.lines().collect::<Vec<&str>>(),
+            vec![
+                "Day 18 has no Rust solution",
+                "Which day? Like 'day 7'",
+                "Day 7: no input at nowhere.txt",
+                "The commands come from stdin, so the input can't come from there too",
+                "Day 7: ../day8/example.txt:1:1: expected a rule like 'light red bags contain 1 bright white bag.', found 'nop +0'",
+            ]
+        );
+
+        // day 10 never met a difference of 2 jolts, but the session goes on
+        let path = std::env::temp_dir().join("aoc-repl-day10.txt");
+        fs::write(&path, "1\n3\n").unwrap();
+        let output = run(Some((10, Some(path))), "part 1\npart 1");
+        let lines = untimed(&output);
+        assert_eq!(lines[1], "Day 10 part 1 panicked: Unexpected diff: 2");
+        assert_eq!(lines[2], lines[1]);
+    }
+}
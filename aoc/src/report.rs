@@ -0,0 +1,356 @@
+//! `aoc report`: runs the whole calendar at once, several days at a time, and sums it up in a
+//! table of answers, timings and whether they match the recorded answers.
+
+use std::{
+    fmt,
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+    time::{Duration, Instant},
+};
+
+use aoc_common::Answer;
+use rayon::prelude::*;
+use serde::Serialize;
+
+use crate::{
+    answers::Answers,
+    config::Config,
+    registry::{self, panic_message, Day},
+};
+
+/// How a part did against `answers.toml`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", content = "detail", rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    Wrong(String),
+    Unverified,
+    NoInput,
+    Failed(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Wrong(expected) => write!(f, "wrong, expected {}", expected),
+            Status::Unverified => write!(f, "unverified"),
+            Status::NoInput => write!(f, "no input"),
+            Status::Failed(error) => write!(f, "failed: {}", error),
+        }
+    }
+}
+
+/// One part of one day, or the whole day when it didn't get as far as the parts.
+#[derive(Debug, Clone, Serialize)]
+pub struct Row {
+    pub day: u32,
+    pub part: Option<u32>,
+    pub answer: Option<String>,
+    pub parse_ms: Option<f64>,
+    pub solve_ms: Option<f64>,
+    #[serde(flatten)]
+    pub status: Status,
+}
+
+impl Row {
+    fn day(day: u32, status: Status) -> Row {
+        Row {
+            day,
+            part: None,
+            answer: None,
+            parse_ms: None,
+            solve_ms: None,
+            status,
+        }
+    }
+}
+
+fn ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Runs `f`, turning a panic into an error, so that one day giving up doesn't take the rest along.
+fn guard<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .map_err(|payload| format!("panicked: {}", panic_message(&*payload)))
+}
+
+fn run_day(config: &Config, answers: &Answers, solvers: &Day) -> Vec<Row> {
+    let day = solvers.day;
+    let source = config.source(day);
+    let input = match source.read() {
+        Some(input) => input,
+        None => return vec![Row::day(day, Status::NoInput)],
+    };
+
+    let start = Instant::now();
+    let parsed = match guard(|| solvers.solver.parse_with(&input, &config.params(day))) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(err)) => {
+            return vec![Row::day(
+                day,
+                Status::Failed(source.locate(err).to_string()),
+            )]
+        }
+        Err(err) => return vec![Row::day(day, Status::Failed(err))],
+    };
+    let parse_ms = ms(start.elapsed());
+
+    let mut rows = vec![];
+    for part in 1..=2 {
+        let start = Instant::now();
+        let answer = guard(|| solvers.solver.part(&parsed, part));
+        let solve_ms = ms(start.elapsed());
+        let (answer, status) = match answer {
+            Ok(Answer::Empty) => continue,
+            Ok(answer) => {
+                let answer = answer.to_string();
+                let status = match answers.get(day, part) {
+                    Some(expected) if expected == answer => Status::Ok,
+                    Some(expected) => Status::Wrong(expected.to_string()),
+                    None => Status::Unverified,
+                };
+                (Some(answer), status)
+            }
+            Err(err) => (None, Status::Failed(err)),
+        };
+        rows.push(Row {
+            day,
+            part: Some(part),
+            answer,
+            parse_ms: Some(parse_ms),
+            solve_ms: Some(solve_ms),
+            status,
+        });
+    }
+    rows
+}
+
+/// Runs `days` on up to `jobs` threads, returning their rows in calendar order. Timings are of
+/// each day on its own thread, so they grow when more days run than there are cores.
+pub fn run(
+    config: &Config,
+    answers: &Answers,
+    days: RangeInclusive<u32>,
+    jobs: usize,
+) -> Result<Vec<Row>, String> {
+    let solvers: Vec<&Day> = days.filter_map(registry::find).collect();
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs)
+        .build()
+        .map_err(|err| err.to_string())?;
+
+    let rows: Vec<Vec<Row>> = pool.install(|| {
+        solvers
+            .par_iter()
+            .map(|solvers| run_day(config, answers, solvers))
+            .collect()
+    });
+    Ok(rows.into_iter().flatten().collect())
+}
+
+/// Whether every part that ran got the recorded answer, or has none recorded.
+pub fn passed(rows: &[Row]) -> bool {
+    rows.iter()
+        .all(|row| !matches!(row.status, Status::Wrong(_) | Status::Failed(_)))
+}
+
+fn cells(row: &Row) -> [String; 6] {
+    let time = |ms: Option<f64>| ms.map_or(String::new(), |ms| format!("{:.3} ms", ms));
+    [
+        row.day.to_string(),
+        row.part.map_or(String::new(), |part| part.to_string()),
+        row.answer.clone().unwrap_or_default(),
+        time(row.parse_ms),
+        time(row.solve_ms),
+        row.status.to_string(),
+    ]
+}
+
+const HEADERS: [&str; 6] = ["Day", "Part", "Answer", "Parse", "Solve", "Status"];
+
+/// The rows lined up in columns, with the timings aligned on the right.
+pub fn table(rows: &[Row]) -> String {
+    let cells: Vec<[String; 6]> = rows.iter().map(cells).collect();
+    let mut widths = HEADERS.map(str::len);
+    for row in &cells {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |row: [&str; 6]| -> String {
+        let mut line = String::new();
+        for (i, (cell, width)) in row.iter().zip(widths).enumerate() {
+            if i > 0 {
+                line.push_str("  ");
+            }
+            match i {
+                0 | 1 | 3 | 4 => line.push_str(&format!("{:>width$}", cell, width = width)),
+                _ => line.push_str(&format!("{:width$}", cell, width = width)),
+            }
+        }
+        line.trim_end().to_string() + "\n"
+    };
+
+    let mut table = line(HEADERS);
+    for row in &cells {
+        table.push_str(&line(row.each_ref().map(String::as_str)));
+    }
+    table
+}
+
+/// The rows as a Markdown table, for the README.
+pub fn markdown(rows: &[Row]) -> String {
+    let mut table = format!(
+        "| {} |\n|----:|----:|:-------|------:|------:|:-------|\n",
+        HEADERS.join(" | ")
+    );
+    for row in rows {
+        let cells = cells(row).map(|cell| cell.replace('|', "\\|"));
+        table.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    table
+}
+
+pub fn json(rows: &[Row]) -> String {
+    serde_json::to_string_pretty(rows).expect("rows serialize") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, fs};
+
+    fn rows() -> Vec<Row> {
+        let dir = env::temp_dir().join("aoc-report");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day1.txt"), "1721\n979\n366\n299\n675\n1456\n").unwrap();
+        fs::write(
+            dir.join("day7.txt"),
+            fs::read_to_string("../day7/example.txt").unwrap(),
+        )
+        .unwrap();
+        fs::write(dir.join("day8.txt"), "nop +0\njmp three\n").unwrap();
+        fs::write(dir.join("day10.txt"), "1\n3\n").unwrap();
+        fs::write(dir.join("day25.txt"), "5764801\n17807724\n").unwrap();
+
+        let config = Config::load(None, Some(dir), &[]).unwrap();
+        let mut answers = Answers::default();
+        answers.set(7, 1, "4".to_string());
+        answers.set(7, 2, "31".to_string());
+        run(&config, &answers, 1..=25, 4).unwrap()
+    }
+
+    #[test]
+    fn test_run() {
+        let rows = rows();
+        let summary: Vec<(u32, Option<u32>, Option<&str>, String)> = rows
+            .iter()
+            .filter(|row| row.status != Status::NoInput)
+            .map(|row| {
+                (
+                    row.day,
+                    row.part,
+                    row.answer.as_deref(),
+                    row.status.to_string(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, Some(1), Some("514579"), "unverified".to_string()),
+                (1, Some(2), Some("241861950"), "unverified".to_string()),
+                (7, Some(1), Some("4"), "ok".to_string()),
+                (7, Some(2), Some("32"), "wrong, expected 31".to_string()),
+                (
+                    8,
+                    None,
+                    None,
+                    format!(
+                        "failed: {}: expected a number, found 'three'",
+                        env::temp_dir()
+                            .join("aoc-report/day8.txt")
+                            .display()
+                            .to_string()
+                            + ":2:5"
+                    )
+                ),
+                (
+                    10,
+                    Some(1),
+                    None,
+                    "failed: panicked: Unexpected diff: 2".to_string()
+                ),
+                (
+                    10,
+                    Some(2),
+                    None,
+                    "failed: panicked: Unexpected diff: 2".to_string()
+                ),
+                (25, Some(1), Some("14897079"), "unverified".to_string()),
+            ]
+        );
+        assert_eq!(
+            rows.iter()
+                .filter(|row| row.status == Status::NoInput)
+                .count(),
+            19
+        );
+        assert!(!passed(&rows));
+        assert!(passed(&rows[..2]));
+    }
+
+    #[test]
+    fn test_formats() {
+        let rows = vec![
+            Row {
+                day: 7,
+                part: Some(1),
+                answer: Some("4".to_string()),
+                parse_ms: Some(0.25),
+                solve_ms: Some(12.5),
+                status: Status::Ok,
+            },
+            Row::day(8, Status::NoInput),
+            Row {
+                day: 23,
+                part: Some(2),
+                answer: Some("149245887792".to_string()),
+                parse_ms: Some(0.002),
+                solve_ms: Some(1234.5678),
+                status: Status::Wrong("1".to_string()),
+            },
+        ];
+        assert_eq!(
+            table(&rows),
+            "\
+Day  Part  Answer           Parse        Solve  Status
+  7     1  4             0.250 ms    12.500 ms  ok
+  8                                             no input
+ 23     2  149245887792  0.002 ms  1234.568 ms  wrong, expected 1
+"
+        );
+        assert_eq!(
+            markdown(&rows[1..]),
+            "\
+| Day | Part | Answer | Parse | Solve | Status |
+|----:|----:|:-------|------:|------:|:-------|
+| 8 |  |  |  |  | no input |
+| 23 | 2 | 149245887792 | 0.002 ms | 1234.568 ms | wrong, expected 1 |
+"
+        );
+        let json: serde_json::Value = serde_json::from_str(&json(&rows)).unwrap();
+        assert_eq!(
+            json[2],
+            serde_json::json!({
+                "day": 23, "part": 2, "answer": "149245887792", "parse_ms": 0.002,
+                "solve_ms": 1234.5678, "status": "wrong", "detail": "1"
+            })
+        );
+        assert_eq!(json[1]["status"], "no_input");
+        assert_eq!(json[1]["part"], serde_json::Value::Null);
+    }
+}
@@ -0,0 +1,162 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The calendar this workspace solves.
+pub const YEAR: u32 = 2020;
+
+const TEMPLATE: &str = include_str!("../../template.rs");
+
+/// The manifest of a new day crate, with the same authors as the runner.
+fn manifest(day: u32) -> String {
+    let authors: Vec<String> = env!("CARGO_PKG_AUTHORS")
+        .split(':')
+        .map(|author| format!("\"{}\"", author))
+        .collect();
+
+    format!(
+        r#"[package]
+name = "day{day}"
+version = "0.1.0"
+authors = [{authors}]
+edition = "2018"
+
+[dependencies]
+aoc-common = {{ path = "../common" }}
+"#,
+        day = day,
+        authors = authors.join(", ")
+    )
+}
+
+fn source(day: u32, year: u32) -> String {
+    TEMPLATE
+        .replace("DayN", &format!("Day{}", day))
+        .replace("dayN", &format!("day{}", day))
+        .replace("day/N", &format!("day/{}", day))
+        .replace("YEAR", &year.to_string())
+}
+
+/// Inserts `line` among the lines that `key` finds a day number in, keeping them in order.
+fn insert_line(
+    text: &str,
+    day: u32,
+    line: &str,
+    key: impl Fn(&str) -> Option<u32>,
+) -> Result<String, String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let keyed: Vec<(usize, u32)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| key(l.trim()).map(|k| (i, k)))
+        .collect();
+    if keyed.iter().any(|(_, k)| *k == day) {
+        return Err(format!("day{} is already there", day));
+    }
+    let last = keyed.last().ok_or("there are no days to add to")?.0;
+    let at = keyed
+        .iter()
+        .find(|(_, k)| *k > day)
+        .map(|(i, _)| *i)
+        .unwrap_or(last + 1);
+    let indent: String = lines[last]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+
+    let mut result: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    result.insert(at, format!("{}{}", indent, line));
+    Ok(result.join("\n") + "\n")
+}
+
+/// The day in `prefix<day>suffix`.
+fn day_between(line: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    line.strip_prefix(prefix)?
+        .split_once(suffix)?
+        .0
+        .parse()
+        .ok()
+}
+
+fn edit(
+    path: PathBuf,
+    edit: impl Fn(&str) -> Result<String, String>,
+) -> Result<(PathBuf, String), String> {
+    let text = fs::read_to_string(&path)
+        .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+    let text = edit(&text).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok((path, text))
+}
+
+/// Creates the crate for `day` under the workspace at `root` and registers it with the workspace
+/// and the runner. Returns the new crate's directory.
+pub fn new_day(root: &Path, day: u32, year: u32) -> Result<PathBuf, String> {
+    let dir = root.join(format!("day{}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    // work out all the registrations before writing any, so a failure leaves nothing half done
+    let edits = vec![
+        edit(root.join("Cargo.toml"), |text| {
+            insert_line(text, day, &format!("\"day{}\",", day), |l| {
+                day_between(l, "\"day", "\"")
+            })
+        })?,
+        edit(root.join("aoc/Cargo.toml"), |text| {
+            let line = format!("day{day} = {{ path = \"../day{day}\" }}", day = day);
+            insert_line(text, day, &line, |l| day_between(l, "day", " = "))
+        })?,
+        edit(root.join("aoc/src/registry.rs"), |text| {
+            let line = format!("day!({day}, day{day}::Day{day}),", day = day);
+            insert_line(text, day, &line, |l| day_between(l, "day!(", ","))
+        })?,
+    ];
+
+    let write = |path: PathBuf, contents: &str| {
+        fs::write(&path, contents).map_err(|e| format!("Could not write {}: {}", path.display(), e))
+    };
+    for (path, text) in edits {
+        write(path, &text)?;
+    }
+    fs::create_dir_all(dir.join("src"))
+        .map_err(|e| format!("Could not create {}: {}", dir.display(), e))?;
+    write(dir.join("Cargo.toml"), &manifest(day))?;
+    write(dir.join("src/lib.rs"), &source(day, year))?;
+    write(dir.join("example.txt"), "")?;
+
+    Ok(dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_line() {
+        let members =
+            "members = [\n    \"aoc\",\n    \"day1\",\n    \"day2\",\n    \"day10\",\n]\n";
+        let key = |l: &str| day_between(l, "\"day", "\"");
+        assert_eq!(
+            insert_line(members, 3, "\"day3\",", key),
+            Ok("members = [\n    \"aoc\",\n    \"day1\",\n    \"day2\",\n    \"day3\",\n    \"day10\",\n]\n".to_string())
+        );
+        assert_eq!(
+            insert_line(members, 11, "\"day11\",", key),
+            Ok("members = [\n    \"aoc\",\n    \"day1\",\n    \"day2\",\n    \"day10\",\n    \"day11\",\n]\n".to_string())
+        );
+        assert!(insert_line(members, 2, "\"day2\",", key).is_err());
+        assert!(insert_line("members = []", 2, "\"day2\",", key).is_err());
+    }
+
+    #[test]
+    fn test_source() {
+        let source = source(7, 2021);
+        assert!(source.starts_with(
+            "//! https://adventofcode.com/2021/day/7, with the input in input/2021/day7.txt\n"
+        ));
+        assert!(source.contains("pub struct Day7;"));
+        assert!(!source.contains("DayN"));
+    }
+}
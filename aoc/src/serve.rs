@@ -0,0 +1,285 @@
+//! `aoc serve`: the solvers behind a small local HTTP server, so that scripts in other languages
+//! can use them without running the command line tool for every input.
+//!
+//! `POST /solve` takes `{"day": 9, "part": 1, "input": "...", "params": {"preamble": 5}}`, where
+//! `part` and `params` are optional, and answers with the answers and how long parsing and each
+//! part took. Answers are strings, since some don't fit in a JSON number. `GET /days` lists the
+//! days there are solutions for.
+
+use std::{
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    thread,
+    time::Instant,
+};
+
+use aoc_common::{Answer, ParseError};
+use serde::{Deserialize, Serialize};
+use tiny_http::{Header, Server};
+
+use crate::{
+    config::Config,
+    registry::{self, panic_message},
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Request {
+    pub day: u32,
+    /// Both parts when not given.
+    pub part: Option<u32>,
+    pub input: String,
+    /// On top of the day's parameters from the config.
+    #[serde(default)]
+    pub params: BTreeMap<String, usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Solved {
+    pub day: u32,
+    pub parse_ms: f64,
+    pub parts: Vec<Part>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Part {
+    pub part: u32,
+    /// `None` for parts without a puzzle, like the second half of day 25.
+    pub answer: Option<String>,
+    pub ms: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Failure {
+    pub error: String,
+    /// Where parsing the input went wrong, when it did.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diagnostic: Option<Diagnostic>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Diagnostic {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub expected: String,
+}
+
+impl Failure {
+    fn new(error: impl Into<String>) -> Failure {
+        Failure {
+            error: error.into(),
+            diagnostic: None,
+        }
+    }
+}
+
+impl From<ParseError> for Failure {
+    fn from(err: ParseError) -> Failure {
+        Failure {
+            error: err.to_string(),
+            diagnostic: Some(Diagnostic {
+                line: err.line,
+                column: err.column,
+                token: err.token,
+                expected: err.expected,
+            }),
+        }
+    }
+}
+
+/// A status code and the JSON to send with it.
+#[derive(Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: &impl Serialize) -> Response {
+        Response {
+            status,
+            body: serde_json::to_string(body).expect("responses serialize"),
+        }
+    }
+}
+
+fn elapsed_ms(start: Instant) -> f64 {
+    start.elapsed().as_secs_f64() * 1000.0
+}
+
+/// Solves `request`, or says why not with the status code to send.
+pub fn solve(config: &Config, request: &Request) -> Result<Solved, (u16, Failure)> {
+    let solvers = registry::find(request.day).ok_or_else(|| {
+        let error = format!("Day {} has no Rust solution", request.day);
+        (404, Failure::new(error))
+    })?;
+    let parts = match request.part {
+        Some(p @ 1..=2) => p..=p,
+        Some(p) => return Err((400, Failure::new(format!("There is no part {}", p)))),
+        None => 1..=2,
+    };
+    let mut params = config.params(request.day);
+    for (key, value) in &request.params {
+        params.set(key.clone(), *value);
+    }
+    let panicked = |what: String, payload: Box<dyn std::any::Any + Send>| {
+        let error = format!("{} panicked: {}", what, panic_message(&*payload));
+        (500, Failure::new(error))
+    };
+
+    let start = Instant::now();
+    let parsed = panic::catch_unwind(|| solvers.solver.parse_with(&request.input, &params))
+        .map_err(|payload| panicked(format!("Parsing day {}", request.day), payload))?
+        .map_err(|err| (422, Failure::from(err)))?;
+    let parse_ms = elapsed_ms(start);
+
+    let mut solved = Solved {
+        day: request.day,
+        parse_ms,
+        parts: vec![],
+    };
+    for p in parts {
+        let start = Instant::now();
+        let answer = panic::catch_unwind(AssertUnwindSafe(|| solvers.solver.part(&parsed, p)))
+            .map_err(|payload| panicked(format!("Day {} part {}", request.day, p), payload))?;
+        solved.parts.push(Part {
+            part: p,
+            answer: match answer {
+                Answer::Empty => None,
+                answer => Some(answer.to_string()),
+            },
+            ms: elapsed_ms(start),
+        });
+    }
+    Ok(solved)
+}
+
+/// Answers one request, whatever the method and path. Any query string is ignored.
+pub fn handle(config: &Config, method: &str, url: &str, body: &str) -> Response {
+    let path = url.split('?').next().unwrap_or(url);
+    match (method, path) {
+        ("POST", "/solve") => {
+            let request: Request = match serde_json::from_str(body) {
+                Ok(request) => request,
+                Err(err) => return Response::json(400, &Failure::new(err.to_string())),
+            };
+            match solve(config, &request) {
+                Ok(solved) => Response::json(200, &solved),
+                Err((status, failure)) => Response::json(status, &failure),
+            }
+        }
+        ("GET", "/days") => {
+            let days: Vec<u32> = registry::DAYS.iter().map(|d| d.day).collect();
+            Response::json(200, &serde_json::json!({ "days": days }))
+        }
+        (_, "/solve") | (_, "/days") => {
+            let error = format!("{} is not allowed on {}", method, path);
+            Response::json(405, &Failure::new(error))
+        }
+        _ => Response::json(404, &Failure::new(format!("Nothing at {}", path))),
+    }
+}
+
+/// Serves requests on `addr` until the process is stopped, a few at a time so that one slow day
+/// doesn't hold up the rest.
+pub fn serve(config: &Config, addr: &str) -> Result<(), String> {
+    let server =
+        Server::http(addr).map_err(|err| format!("Could not listen on {}: {}", addr, err))?;
+    println!("Serving the solvers on http://{}", server.server_addr());
+    let workers = thread::available_parallelism().map_or(4, |n| n.get());
+    let json = Header::from_bytes("Content-Type", "application/json").unwrap();
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            scope.spawn(|| {
+                for mut request in server.incoming_requests() {
+                    let mut body = String::new();
+                    let response = match request.as_reader().read_to_string(&mut body) {
+                        Ok(_) => handle(config, request.method().as_str(), request.url(), &body),
+                        Err(err) => Response::json(400, &Failure::new(err.to_string())),
+                    };
+                    let reply = tiny_http::Response::from_string(response.body)
+                        .with_status_code(response.status)
+                        .with_header(json.clone());
+                    if let Err(err) = request.respond(reply) {
+                        eprintln!("Could not answer a request: {}", err);
+                    }
+                }
+            });
+        }
+    });
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn post(body: Value) -> (u16, Value) {
+        let config = Config::load(None, None, &[]).unwrap();
+        let response = handle(&config, "POST", "/solve", &body.to_string());
+        (
+            response.status,
+            serde_json::from_str(&response.body).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = "1721\n979\n366\n299\n675\n1456";
+        let (status, body) = post(json!({ "day": 1, "input": input }));
+        assert_eq!(status, 200);
+        assert_eq!(body["parts"][0]["answer"], "514579");
+        assert_eq!(body["parts"][1]["answer"], "241861950");
+        assert!(body["parse_ms"].as_f64().unwrap() >= 0.0);
+
+        let input = "35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n102\n117\n150\n182\n127\n219";
+        let request = json!({ "day": 9, "part": 1, "input": input, "params": { "preamble": 5 } });
+        let (status, body) = post(request);
+        assert_eq!(status, 200);
+        assert_eq!(
+            body["parts"],
+            json!([{ "part": 1, "answer": "127", "ms": body["parts"][0]["ms"] }])
+        );
+
+        let (_, body) = post(json!({ "day": 25, "part": 2, "input": "5764801\n17807724" }));
+        assert_eq!(body["parts"][0]["answer"], Value::Null);
+    }
+
+    #[test]
+    fn test_errors() {
+        let (status, body) = post(json!({ "day": 1, "input": "1721\n97x" }));
+        assert_eq!(status, 422);
+        assert_eq!(
+            body["diagnostic"],
+            json!({ "line": 2, "column": 1, "token": "97x", "expected": "a number" })
+        );
+
+        let (status, body) = post(json!({ "day": 18, "input": "1 + 2" }));
+        assert_eq!(
+            (status, body["error"].as_str()),
+            (404, Some("Day 18 has no Rust solution"))
+        );
+        assert_eq!(post(json!({ "day": 1, "part": 3, "input": "" })).0, 400);
+        assert_eq!(post(json!({ "day": 1 })).0, 400);
+
+        // a jump of 2 jolts is allowed, but day 10 never met one
+        let (status, body) = post(json!({ "day": 10, "input": "1\n3" }));
+        assert_eq!(status, 500);
+        assert_eq!(body["error"], "Day 10 part 1 panicked: Unexpected diff: 2");
+
+        let config = Config::load(None, None, &[]).unwrap();
+        assert_eq!(handle(&config, "GET", "/solve", "").status, 405);
+        assert_eq!(handle(&config, "GET", "/", "").status, 404);
+        assert_eq!(handle(&config, "GET", "/days?x=1", "").status, 200);
+        let request = json!({ "day": 1, "input": "1721\n299" }).to_string();
+        assert_eq!(
+            handle(&config, "POST", "/solve?pretty", &request).status,
+            200
+        );
+        let days: Value = serde_json::from_str(&handle(&config, "GET", "/days", "").body).unwrap();
+        assert_eq!(days["days"].as_array().unwrap().len(), 24);
+    }
+}
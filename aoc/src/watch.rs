@@ -0,0 +1,283 @@
+//! `aoc watch`: rebuilds a day whenever its code, its examples or its input change, runs its tests
+//! and both parts, and says which answers changed since the last run.
+//!
+//! The solvers are compiled into this binary, so the work is done by `cargo test` and
+//! `cargo run -- run` in child processes, which pick up the new code.
+
+use std::{
+    collections::BTreeMap,
+    env,
+    path::{self, Path, PathBuf},
+    process::{Command, Stdio},
+    sync::mpsc,
+    time::Duration,
+};
+
+use notify::{RecursiveMode, Watcher};
+
+use crate::config::{Config, Source};
+
+/// How long to wait for an editor to finish writing before rebuilding.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// The files that make up a day: the Rust files under `src/`, the examples next to it and the
+/// real input.
+pub struct Files {
+    dir: PathBuf,
+    input: Option<PathBuf>,
+}
+
+impl Files {
+    pub fn new(config: &Config, day: u32) -> Files {
+        Files {
+            dir: PathBuf::from(format!("day{}", day)),
+            input: match config.source(day) {
+                Source::File(path) => Some(path),
+                _ => None,
+            },
+        }
+    }
+
+    /// Whether a change to `path` calls for a new run.
+    pub fn contains(&self, path: &Path) -> bool {
+        let (path, dir) = (absolute(path), absolute(&self.dir));
+        let name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("");
+
+        let source = path.starts_with(dir.join("src")) && name.ends_with(".rs");
+        let example =
+            path.parent() == Some(&dir) && name.starts_with("example") && name.ends_with(".txt");
+        source || example || self.input.as_deref().map(absolute) == Some(path)
+    }
+
+    /// The directories to watch, which also catches files that editors replace rather than write.
+    fn watched(&self) -> Vec<(PathBuf, RecursiveMode)> {
+        let mut dirs = vec![
+            (self.dir.join("src"), RecursiveMode::Recursive),
+            (self.dir.clone(), RecursiveMode::NonRecursive),
+        ];
+        if let Some(dir) = self.input.as_deref().and_then(Path::parent) {
+            if dir.is_dir() {
+                dirs.push((dir.to_path_buf(), RecursiveMode::NonRecursive));
+            }
+        }
+        dirs
+    }
+}
+
+/// `path` from the root, so that the paths the watcher reports compare to the ones given, which
+/// can be either.
+fn absolute(path: &Path) -> PathBuf {
+    path::absolute(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+/// `path` from the current directory, as the watcher reports absolute paths.
+fn relative(path: &Path) -> &Path {
+    env::current_dir()
+        .ok()
+        .and_then(|dir| path.strip_prefix(dir).ok())
+        .unwrap_or(path)
+}
+
+/// The answers in the output of `aoc run`, by part.
+pub fn answers(output: &str) -> BTreeMap<u32, String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, answer) = line.split_once(": ")?;
+            let part = name.split_once(" part ")?.1.parse().ok()?;
+            Some((part, answer.to_string()))
+        })
+        .collect()
+}
+
+/// Each part's answer, with what it was on the previous run when that was different.
+pub fn compare(before: Option<&BTreeMap<u32, String>>, now: &BTreeMap<u32, String>) -> Vec<String> {
+    let mut parts: Vec<u32> = now.keys().copied().collect();
+    if let Some(before) = before {
+        parts.extend(before.keys().filter(|part| !now.contains_key(part)));
+    }
+    parts.sort_unstable();
+
+    parts
+        .into_iter()
+        .map(|part| {
+            let old = before.and_then(|before| before.get(&part));
+            match (now.get(&part), old) {
+                (Some(answer), _) if before.is_none() => format!("Part {}: {}", part, answer),
+                (Some(answer), Some(old)) if answer == old => {
+                    format!("Part {}: {} (unchanged)", part, answer)
+                }
+                (Some(answer), Some(old)) => format!("Part {}: {} (was {})", part, answer, old),
+                (Some(answer), None) => format!("Part {}: {} (new)", part, answer),
+                (None, old) => format!("Part {}: no answer (was {})", part, old.unwrap()),
+            }
+        })
+        .collect()
+}
+
+fn cargo() -> Command {
+    Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+}
+
+/// Tests the day and runs it, returning its answers, or `None` when it didn't build or run.
+fn run_once(day: u32, forward: &[String]) -> Option<BTreeMap<u32, String>> {
+    let package = format!("day{}", day);
+    match cargo().args(["test", "-q", "-p", &package]).status() {
+        Ok(status) if status.success() => println!("Tests passed"),
+        Ok(_) => println!("Tests failed"),
+        Err(err) => {
+            eprintln!("Could not run cargo: {}", err);
+            return None;
+        }
+    }
+
+    let output = cargo()
+        .args([
+            "run",
+            "-q",
+            "--release",
+            "-p",
+            "aoc",
+            "--",
+            "run",
+            &day.to_string(),
+        ])
+        .args(forward)
+        .stderr(Stdio::inherit())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        println!("Day {} did not run", day);
+        return None;
+    }
+    Some(answers(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Runs the day now and after every change to its files, until interrupted. `forward` are the
+/// options to pass on to `aoc run`, like `--input-dir`.
+pub fn watch(config: &Config, day: u32, forward: &[String]) -> Result<(), String> {
+    let files = Files::new(config, day);
+    if !files.dir.join("src").is_dir() {
+        let src = files.dir.join("src");
+        return Err(format!(
+            "There is no {}, run this from the workspace root",
+            src.display()
+        ));
+    }
+
+    let (sender, events) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(|err| err.to_string())?;
+    for (dir, mode) in files.watched() {
+        watcher
+            .watch(&dir, mode)
+            .map_err(|err| format!("Could not watch {}: {}", dir.display(), err))?;
+    }
+
+    let mut previous = None;
+    let mut changed = vec![];
+    loop {
+        match changed.as_slice() {
+            [] => println!("== Day {} ==", day),
+            paths => {
+                let names: Vec<String> = paths
+                    .iter()
+                    .map(|path: &PathBuf| relative(path).display().to_string())
+                    .collect();
+                println!("== Day {}: {} changed ==", day, names.join(", "));
+            }
+        }
+        if let Some(answers) = run_once(day, forward) {
+            for line in compare(previous.as_ref(), &answers) {
+                println!("{}", line);
+            }
+            previous = Some(answers);
+        }
+
+        // wait for a change, then for the rest of the burst an editor's save makes
+        changed.clear();
+        while changed.is_empty() {
+            let mut burst = vec![events.recv().map_err(|err| err.to_string())?];
+            while let Ok(event) = events.recv_timeout(SETTLE) {
+                burst.push(event);
+            }
+            for event in burst {
+                let event = event.map_err(|err| err.to_string())?;
+                // building reads the files too, which mustn't start another run
+                if event.kind.is_access() || event.kind.is_other() {
+                    continue;
+                }
+                for path in event.paths {
+                    if files.contains(&path) && !changed.contains(&path) {
+                        changed.push(path);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains() {
+        let files = Files {
+            dir: PathBuf::from("day7"),
+            input: Some(PathBuf::from("input/2020/day7.txt")),
+        };
+        assert!(files.contains(Path::new("day7/src/lib.rs")));
+        assert!(files.contains(Path::new("day7/src/bags/rules.rs")));
+        assert!(files.contains(Path::new("day7/example2.txt")));
+        assert!(files.contains(Path::new("input/2020/day7.txt")));
+        assert!(files.contains(&env::current_dir().unwrap().join("day7/src/lib.rs")));
+
+        assert!(!files.contains(Path::new("day7/src/.lib.rs.swp")));
+        assert!(!files.contains(Path::new("day7/notes.txt")));
+        assert!(!files.contains(Path::new("day17/src/lib.rs")));
+        assert!(!files.contains(Path::new("input/2020/day8.txt")));
+        assert!(files.contains(&env::current_dir().unwrap().join("input/2020/day7.txt")));
+
+        // with an absolute --input-dir, here under the current directory
+        let cwd = env::current_dir().unwrap();
+        let files = Files {
+            dir: PathBuf::from("day7"),
+            input: Some(cwd.join("input/2020/day7.txt")),
+        };
+        assert!(files.contains(&cwd.join("input/2020/day7.txt")));
+        assert!(files.contains(&cwd.join("input/2020/./day7.txt")));
+        assert!(files.contains(Path::new("input/2020/day7.txt")));
+        assert!(!files.contains(&cwd.join("input/2020/day8.txt")));
+    }
+
+    #[test]
+    fn test_compare() {
+        let before = answers("Day 7 part 1: 4\nDay 7 part 2: 32\n");
+        assert_eq!(
+            before,
+            vec![(1, "4".to_string()), (2, "32".to_string())]
+                .into_iter()
+                .collect()
+        );
+        assert_eq!(compare(None, &before), vec!["Part 1: 4", "Part 2: 32"]);
+
+        let now = answers("Day 7 part 1: 5\nDay 7 part 2: 32\n");
+        assert_eq!(
+            compare(Some(&before), &now),
+            vec!["Part 1: 5 (was 4)", "Part 2: 32 (unchanged)"]
+        );
+
+        let now = answers("Day 7 part 2: 32\n");
+        assert_eq!(
+            compare(Some(&now), &before),
+            vec!["Part 1: 4 (new)", "Part 2: 32 (unchanged)"]
+        );
+        assert_eq!(
+            compare(Some(&before), &now),
+            vec!["Part 1: no answer (was 4)", "Part 2: 32 (unchanged)"]
+        );
+    }
+}
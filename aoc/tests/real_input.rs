@@ -0,0 +1,113 @@
+//! Runs every day on its real input and checks the answers recorded in `answers.toml`.
+//!
+//! The inputs live in the private `input` submodule, so days whose input is not there are reported
+//! as skipped rather than failed. This has its own harness because libtest can't skip at runtime.
+
+use std::{env, process, thread};
+
+use aoc::{
+    answers::Answers,
+    config::{Config, Source},
+    registry::{self, Day},
+};
+use aoc_common::string_from_file;
+
+enum Outcome {
+    Ok,
+    Skipped(String),
+    Failed(String),
+}
+
+fn check(config: &Config, answers: &Answers, day: &Day) -> Outcome {
+    let expected: Vec<(u32, &str)> = (1..=2)
+        .filter_map(|p| answers.get(day.day, p).map(|answer| (p, answer)))
+        .collect();
+    if expected.is_empty() {
+        return Outcome::Skipped("no recorded answers".to_string());
+    }
+
+    let input = match config.source(day.day) {
+        Source::Inline(input) => input,
+        Source::File(path) if path.exists() => string_from_file(path),
+        Source::File(path) => return Outcome::Skipped(format!("no input at {}", path.display())),
+        Source::Stdin => unreachable!("the config never reads stdin"),
+    };
+    let parsed = match day.solver.parse_with(&input, &config.params(day.day)) {
+        Ok(parsed) => parsed,
+        Err(err) => return Outcome::Failed(err.to_string()),
+    };
+
+    let mut wrong = Vec::new();
+    for (p, expected) in expected {
+        let answer = day.solver.part(&parsed, p).to_string();
+        if answer != expected {
+            wrong.push(format!("part {}: expected {}, got {}", p, expected, answer));
+        }
+    }
+    if wrong.is_empty() {
+        Outcome::Ok
+    } else {
+        Outcome::Failed(wrong.join("; "))
+    }
+}
+
+fn main() {
+    // aoc.toml, answers.toml and the inputs are all relative to the workspace root
+    env::set_current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/..")).unwrap();
+    let config = Config::load(None, None, &[]).unwrap();
+    let answers = Answers::load(&config.answers).unwrap();
+    // like libtest, only run the tests whose name contains the filter, e.g. `cargo test day23`
+    let filter = env::args().skip(1).find(|arg| !arg.starts_with('-'));
+
+    let days: Vec<&Day> = registry::DAYS
+        .iter()
+        .filter(|day| match &filter {
+            Some(filter) => format!("day{}", day.day).contains(filter.as_str()),
+            None => true,
+        })
+        .collect();
+    // a few days take seconds in a debug build, so run them all at once
+    let (config, answers) = (&config, &answers);
+    let outcomes: Vec<Outcome> = thread::scope(|s| {
+        let handles: Vec<_> = days
+            .iter()
+            .map(|day| s.spawn(move || check(config, answers, day)))
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| match handle.join() {
+                Ok(outcome) => outcome,
+                Err(_) => Outcome::Failed("panicked".to_string()),
+            })
+            .collect()
+    });
+
+    let (mut passed, mut skipped, mut failed) = (0, 0, 0);
+    for (day, outcome) in days.iter().zip(outcomes) {
+        match outcome {
+            Outcome::Ok => {
+                println!("test day{} ... ok", day.day);
+                passed += 1;
+            }
+            Outcome::Skipped(reason) => {
+                println!("test day{} ... skipped, {}", day.day, reason);
+                skipped += 1;
+            }
+            Outcome::Failed(reason) => {
+                println!("test day{} ... FAILED, {}", day.day, reason);
+                failed += 1;
+            }
+        }
+    }
+
+    println!(
+        "\ntest result: {}. {} passed; {} failed; {} skipped\n",
+        if failed == 0 { "ok" } else { "FAILED" },
+        passed,
+        failed,
+        skipped
+    );
+    if failed > 0 {
+        process::exit(1);
+    }
+}
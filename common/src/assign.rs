@@ -0,0 +1,293 @@
+//! Giving each variable one of its candidate values, with no two variables sharing a value, when
+//! the puzzle promises there is exactly one way to do it.
+//!
+//! Variables with a single candidate are settled first, which takes that value away from all the
+//! others, and that can leave more variables with a single candidate. Whatever propagation leaves
+//! unsettled is solved as a bipartite matching with Hopcroft-Karp, which tells apart there being no
+//! assignment from there being more than one.
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    error::Error,
+    fmt,
+    hash::Hash,
+};
+
+/// Why there isn't exactly one assignment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssignError<V> {
+    /// There is no assignment at all, and this variable is one that can't get a value.
+    Infeasible(V),
+    /// There is more than one, and this variable gets different values in them.
+    Ambiguous(V),
+}
+
+impl<V: fmt::Display> fmt::Display for AssignError<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssignError::Infeasible(v) => write!(f, "no value left for {}", v),
+            AssignError::Ambiguous(v) => write!(f, "more than one possible value for {}", v),
+        }
+    }
+}
+
+impl<V: fmt::Debug + fmt::Display> Error for AssignError<V> {}
+
+/// The only assignment of a value to every variable in `candidates` that picks each variable's
+/// value from its candidates and gives no two variables the same value. There may be values left
+/// over.
+pub fn assign<V, X>(candidates: &HashMap<V, HashSet<X>>) -> Result<HashMap<V, X>, AssignError<V>>
+where
+    V: Clone + Eq + Hash + Ord,
+    X: Clone + Eq + Hash + Ord,
+{
+    // sorted, so that errors name the same variable every time
+    let mut vars: Vec<&V> = candidates.keys().collect();
+    vars.sort();
+    let mut values: Vec<&X> = candidates.values().flatten().collect();
+    values.sort();
+    values.dedup();
+    let index: HashMap<&X, usize> = values.iter().enumerate().map(|(i, x)| (*x, i)).collect();
+
+    let mut edges: Vec<Vec<usize>> = vars
+        .iter()
+        .map(|v| {
+            let mut edges: Vec<usize> = candidates[*v].iter().map(|x| index[x]).collect();
+            edges.sort_unstable();
+            edges
+        })
+        .collect();
+
+    let assigned = propagate(&mut edges).map_err(|v| AssignError::Infeasible(vars[v].clone()))?;
+    let mut matching = Matching::new(&edges, values.len());
+    for (v, x) in assigned.iter().enumerate() {
+        if let Some(x) = x {
+            matching.pair(v, *x);
+        }
+    }
+    if let Some(v) = matching.hopcroft_karp() {
+        return Err(AssignError::Infeasible(vars[v].clone()));
+    }
+    if let Some(v) = matching.ambiguous() {
+        return Err(AssignError::Ambiguous(vars[v].clone()));
+    }
+
+    Ok(vars
+        .iter()
+        .enumerate()
+        .map(|(v, var)| ((*var).clone(), values[matching.value[v].unwrap()].clone()))
+        .collect())
+}
+
+/// Settles every variable that has a single candidate left, removing its value from the other
+/// variables, until there are none. Returns the settled values, or a variable with no candidates.
+fn propagate(edges: &mut [Vec<usize>]) -> Result<Vec<Option<usize>>, usize> {
+    let mut assigned = vec![None; edges.len()];
+    let mut queue: VecDeque<usize> = (0..edges.len()).collect();
+
+    while let Some(v) = queue.pop_front() {
+        if assigned[v].is_some() {
+            continue;
+        }
+        match edges[v][..] {
+            [] => return Err(v),
+            [x] => {
+                assigned[v] = Some(x);
+                for (other, candidates) in edges.iter_mut().enumerate() {
+                    if other != v && candidates.contains(&x) {
+                        candidates.retain(|c| *c != x);
+                        queue.push_back(other);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(assigned)
+}
+
+/// A matching between variables and values, each variable having edges to its candidates.
+struct Matching<'a> {
+    edges: &'a [Vec<usize>],
+    value: Vec<Option<usize>>,
+    var: Vec<Option<usize>>,
+}
+
+impl<'a> Matching<'a> {
+    fn new(edges: &'a [Vec<usize>], values: usize) -> Matching<'a> {
+        Matching {
+            edges,
+            value: vec![None; edges.len()],
+            var: vec![None; values],
+        }
+    }
+
+    fn pair(&mut self, v: usize, x: usize) {
+        self.value[v] = Some(x);
+        self.var[x] = Some(v);
+    }
+
+    /// Grows the matching to a maximum one, returning a variable that is still unmatched, if any.
+    fn hopcroft_karp(&mut self) -> Option<usize> {
+        loop {
+            // layer the free variables and those reachable from them along alternating paths
+            let mut layer: Vec<Option<usize>> = self
+                .value
+                .iter()
+                .map(|x| if x.is_none() { Some(0) } else { None })
+                .collect();
+            let mut queue: VecDeque<usize> = (0..self.edges.len())
+                .filter(|v| self.value[*v].is_none())
+                .collect();
+            let mut found = false;
+            while let Some(v) = queue.pop_front() {
+                for x in &self.edges[v] {
+                    match self.var[*x] {
+                        None => found = true,
+                        Some(w) if layer[w].is_none() => {
+                            layer[w] = Some(layer[v].unwrap() + 1);
+                            queue.push_back(w);
+                        }
+                        Some(_) => {}
+                    }
+                }
+            }
+            if !found {
+                break;
+            }
+
+            // then augment along shortest paths, which never share a vertex
+            for v in 0..self.edges.len() {
+                if self.value[v].is_none() {
+                    self.augment_layered(v, &mut layer);
+                }
+            }
+        }
+
+        (0..self.edges.len()).find(|v| self.value[*v].is_none())
+    }
+
+    fn augment_layered(&mut self, v: usize, layer: &mut [Option<usize>]) -> bool {
+        for i in 0..self.edges[v].len() {
+            let x = self.edges[v][i];
+            let next = match self.var[x] {
+                None => true,
+                Some(w) => {
+                    layer[v].is_some_and(|l| layer[w] == Some(l + 1))
+                        && self.augment_layered(w, layer)
+                }
+            };
+            if next {
+                self.pair(v, x);
+                return true;
+            }
+        }
+        // a dead end, so don't come back this way in this phase
+        layer[v] = None;
+        false
+    }
+
+    /// A variable that can be matched to another value while every variable stays matched, if
+    /// there is one.
+    fn ambiguous(&mut self) -> Option<usize> {
+        (0..self.edges.len()).find(|v| {
+            // free v's value, which may end up back with another variable, but not with v
+            let x = self.value[*v].unwrap();
+            self.var[x] = None;
+            let mut visited = vec![false; self.var.len()];
+            let rematched = self.edges[*v]
+                .iter()
+                .any(|y| *y != x && self.alternating_path(*y, &mut visited));
+            self.var[x] = Some(*v);
+            rematched
+        })
+    }
+
+    /// Whether there is an alternating path from value `x` to a free value that avoids `visited`.
+    /// Only looks, leaving the matching as it is.
+    fn alternating_path(&self, x: usize, visited: &mut [bool]) -> bool {
+        if visited[x] {
+            return false;
+        }
+        visited[x] = true;
+        match self.var[x] {
+            None => true,
+            Some(w) => self.edges[w]
+                .iter()
+                .any(|y| self.alternating_path(*y, visited)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates(map: &[(&'static str, &[u32])]) -> HashMap<&'static str, HashSet<u32>> {
+        map.iter()
+            .map(|(v, xs)| (*v, xs.iter().copied().collect()))
+            .collect()
+    }
+
+    #[test]
+    fn test_propagation() {
+        // day 16's example: seat has to be 2, which leaves class with 1 and row with 0
+        let solved = assign(&candidates(&[
+            ("row", &[0, 1, 2]),
+            ("class", &[1, 2]),
+            ("seat", &[2]),
+        ]))
+        .unwrap();
+        assert_eq!(solved, [("row", 0), ("class", 1), ("seat", 2)].into());
+    }
+
+    #[test]
+    fn test_leftover_values() {
+        let solved = assign(&candidates(&[
+            ("a", &[0, 1]),
+            ("b", &[1, 2, 3]),
+            ("c", &[1]),
+        ]));
+        assert_eq!(solved, Err(AssignError::Ambiguous("b")));
+        let solved = assign(&candidates(&[("a", &[0, 1]), ("b", &[1, 2]), ("c", &[2])]));
+        assert_eq!(solved, Ok([("a", 0), ("b", 1), ("c", 2)].into()));
+    }
+
+    #[test]
+    fn test_matching() {
+        // nothing to propagate, but going round the cycle gives a second assignment
+        let solved = assign(&candidates(&[
+            ("a", &[0, 1]),
+            ("b", &[1, 2]),
+            ("c", &[2, 0]),
+        ]));
+        assert_eq!(solved, Err(AssignError::Ambiguous("a")));
+
+        // three variables with two values between them
+        let solved = assign(&candidates(&[
+            ("a", &[0, 1]),
+            ("b", &[0, 1]),
+            ("c", &[0, 1]),
+            ("d", &[2, 3]),
+        ]));
+        assert_eq!(solved, Err(AssignError::Infeasible("c")));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            assign(&candidates(&[("a", &[0]), ("b", &[0])])),
+            Err(AssignError::Infeasible("b"))
+        );
+        assert_eq!(
+            assign(&candidates(&[("a", &[])])),
+            Err(AssignError::Infeasible("a"))
+        );
+        assert_eq!(
+            AssignError::Ambiguous("row").to_string(),
+            "more than one possible value for row"
+        );
+        assert_eq!(assign::<&str, u32>(&HashMap::new()), Ok(HashMap::new()));
+    }
+}
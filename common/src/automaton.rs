@@ -0,0 +1,231 @@
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+/// Where the cells of an automaton live, and which cells neighbour each other.
+pub trait Space {
+    type Cell: Copy + Eq + Hash;
+
+    fn neighbours(&self, cell: &Self::Cell) -> Vec<Self::Cell>;
+
+    /// Every cell of a finite space. Only needed when cells can come alive without any live
+    /// neighbours; otherwise just the live cells and their neighbours are looked at.
+    fn cells(&self) -> Option<Vec<Self::Cell>> {
+        None
+    }
+}
+
+/// The infinite square lattice in `N` dimensions, where each cell neighbours the `3^N - 1` cells
+/// that differ by at most one in every coordinate.
+#[derive(Debug, Clone)]
+pub struct Lattice<const N: usize> {
+    offsets: Vec<[i64; N]>,
+}
+
+impl<const N: usize> Lattice<N> {
+    pub fn new() -> Lattice<N> {
+        let mut offsets = vec![[0; N]];
+        for d in 0..N {
+            offsets = offsets
+                .iter()
+                .flat_map(|offset| {
+                    (-1..=1).map(move |delta| {
+                        let mut offset = *offset;
+                        offset[d] = delta;
+                        offset
+                    })
+                })
+                .collect();
+        }
+        offsets.retain(|offset| offset.iter().any(|d| *d != 0));
+
+        Lattice { offsets }
+    }
+}
+
+impl<const N: usize> Default for Lattice<N> {
+    fn default() -> Lattice<N> {
+        Lattice::new()
+    }
+}
+
+impl<const N: usize> Space for Lattice<N> {
+    type Cell = [i64; N];
+
+    fn neighbours(&self, cell: &[i64; N]) -> Vec<[i64; N]> {
+        self.offsets
+            .iter()
+            .map(|offset| {
+                let mut neighbour = *cell;
+                for (c, d) in neighbour.iter_mut().zip(offset.iter()) {
+                    *c += d;
+                }
+                neighbour
+            })
+            .collect()
+    }
+}
+
+/// Decides whether a cell is alive in the next generation, from whether it is alive now and how
+/// many of its neighbours are.
+pub trait Rule {
+    fn next(&self, alive: bool, neighbours: usize) -> bool;
+}
+
+impl<F: Fn(bool, usize) -> bool> Rule for F {
+    fn next(&self, alive: bool, neighbours: usize) -> bool {
+        self(alive, neighbours)
+    }
+}
+
+/// A rule like the Game of Life's: dead cells with a `birth` number of live neighbours come alive,
+/// live cells with a `survival` number of them stay alive, and all others are dead.
+#[derive(Debug, Clone, Copy)]
+pub struct Life {
+    pub birth: &'static [usize],
+    pub survival: &'static [usize],
+}
+
+impl Rule for Life {
+    fn next(&self, alive: bool, neighbours: usize) -> bool {
+        if alive {
+            self.survival.contains(&neighbours)
+        } else {
+            self.birth.contains(&neighbours)
+        }
+    }
+}
+
+/// A cellular automaton that only stores its live cells.
+#[derive(Debug, Clone)]
+pub struct Automaton<S: Space> {
+    space: S,
+    alive: HashSet<S::Cell>,
+}
+
+impl<S: Space> Automaton<S> {
+    pub fn new(space: S, alive: impl IntoIterator<Item = S::Cell>) -> Automaton<S> {
+        Automaton {
+            space,
+            alive: alive.into_iter().collect(),
+        }
+    }
+
+    pub fn is_alive(&self, cell: &S::Cell) -> bool {
+        self.alive.contains(cell)
+    }
+
+    pub fn alive(&self) -> impl Iterator<Item = &S::Cell> {
+        self.alive.iter()
+    }
+
+    pub fn count(&self) -> usize {
+        self.alive.len()
+    }
+
+    /// Moves on one generation, returning whether any cell changed.
+    pub fn step(&mut self, rule: &impl Rule) -> bool {
+        let mut counts: HashMap<S::Cell, usize> = HashMap::new();
+        for cell in &self.alive {
+            for neighbour in self.space.neighbours(cell) {
+                *counts.entry(neighbour).or_default() += 1;
+            }
+        }
+
+        let candidates: HashSet<S::Cell> = match self.space.cells() {
+            Some(cells) => cells.into_iter().collect(),
+            None => self.alive.iter().chain(counts.keys()).copied().collect(),
+        };
+        let alive: HashSet<S::Cell> = candidates
+            .into_iter()
+            .filter(|cell| {
+                let neighbours = counts.get(cell).copied().unwrap_or(0);
+                rule.next(self.alive.contains(cell), neighbours)
+            })
+            .collect();
+
+        let changed = alive != self.alive;
+        self.alive = alive;
+        changed
+    }
+
+    pub fn run(&mut self, rule: &impl Rule, generations: usize) {
+        for _ in 0..generations {
+            self.step(rule);
+        }
+    }
+
+    /// Steps until nothing changes any more, returning the number of generations that took.
+    pub fn settle(&mut self, rule: &impl Rule) -> usize {
+        let mut generations = 0;
+        while self.step(rule) {
+            generations += 1;
+        }
+        generations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONWAY: Life = Life {
+        birth: &[3],
+        survival: &[2, 3],
+    };
+
+    #[test]
+    fn test_lattice() {
+        assert_eq!(Lattice::<1>::new().neighbours(&[5]), vec![[4], [6]]);
+        assert_eq!(Lattice::<2>::new().neighbours(&[0, 0]).len(), 8);
+        assert_eq!(Lattice::<3>::new().neighbours(&[0, 0, 0]).len(), 26);
+        assert_eq!(Lattice::<4>::new().neighbours(&[0, 0, 0, 0]).len(), 80);
+    }
+
+    #[test]
+    fn test_blinker() {
+        let mut life = Automaton::new(Lattice::<2>::new(), vec![[0, 1], [1, 1], [2, 1]]);
+        assert!(life.step(&CONWAY));
+        assert!(life.is_alive(&[1, 0]) && life.is_alive(&[1, 1]) && life.is_alive(&[1, 2]));
+        assert_eq!(life.count(), 3);
+        life.step(&CONWAY);
+        assert!(life.is_alive(&[0, 1]) && life.is_alive(&[2, 1]));
+    }
+
+    #[test]
+    fn test_settle() {
+        // a block is still life, and a lone cell dies straight away
+        let mut life = Automaton::new(
+            Lattice::<2>::new(),
+            vec![[0, 0], [0, 1], [1, 0], [1, 1], [5, 5]],
+        );
+        assert_eq!(life.settle(&CONWAY), 1);
+        assert_eq!(life.count(), 4);
+        assert_eq!(life.settle(&|alive, _| alive), 0);
+    }
+
+    struct Ring(i64);
+
+    impl Space for Ring {
+        type Cell = i64;
+
+        fn neighbours(&self, cell: &i64) -> Vec<i64> {
+            vec![(cell + 1).rem_euclid(self.0), (cell - 1).rem_euclid(self.0)]
+        }
+
+        fn cells(&self) -> Option<Vec<i64>> {
+            Some((0..self.0).collect())
+        }
+    }
+
+    #[test]
+    fn test_finite_space() {
+        // cells with no live neighbours at all come alive, which needs to know every cell
+        let mut ring = Automaton::new(Ring(5), vec![0]);
+        ring.step(&|alive: bool, n: usize| !alive && n == 0);
+        let mut alive: Vec<i64> = ring.alive().copied().collect();
+        alive.sort_unstable();
+        assert_eq!(alive, vec![2, 3]);
+    }
+}
@@ -0,0 +1,107 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Why some input could not be parsed, pointing at the offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The file the input came from, when known.
+    pub file: Option<String>,
+    /// 1-based line of the token.
+    pub line: usize,
+    /// 1-based column of the token, in characters.
+    pub column: usize,
+    pub token: String,
+    /// What we wanted to find instead, e.g. "a number" or "an instruction like 'acc +1'".
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error for `token`, which must be a slice of `input` so that its position can be
+    /// worked out. Slices returned by `lines`, `split`, `trim` and regex captures all are.
+    pub fn new(input: &str, token: &str, expected: impl Into<String>) -> ParseError {
+        let start = input.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| *offset + token.len() <= input.len());
+        debug_assert!(offset.is_some(), "'{}' is not part of the input", token);
+        let before = &input[..offset.unwrap_or(0)];
+
+        ParseError {
+            file: None,
+            line: before.matches('\n').count() + 1,
+            column: before.chars().rev().take_while(|c| *c != '\n').count() + 1,
+            token: token.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Names the file the input came from, for the diagnostic.
+    pub fn in_file(mut self, file: impl Into<String>) -> ParseError {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}", file, self.line, self.column)?,
+            None => write!(f, "line {}, column {}", self.line, self.column)?,
+        }
+        if self.token.is_empty() {
+            write!(f, ": expected {}, found nothing", self.expected)
+        } else {
+            write!(f, ": expected {}, found '{}'", self.expected, self.token)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::new(input, token, "a number"))
+}
+
+/// Returns the captures of `re` on `line`, or an error saying a line should look like `expected`.
+pub fn captures<'a>(
+    input: &str,
+    line: &'a str,
+    re: &regex::Regex,
+    expected: &str,
+) -> Result<regex::Captures<'a>, ParseError> {
+    re.captures(line)
+        .ok_or_else(|| ParseError::new(input, line, expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position() {
+        let input = "nop +0\nacc +1\njmp +x";
+        let token = &input[18..];
+        let err = ParseError::new(input, token, "a number");
+        assert_eq!((err.line, err.column), (3, 5));
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 5: expected a number, found '+x'"
+        );
+        assert_eq!(
+            err.in_file("input/2020/day8.txt").to_string(),
+            "input/2020/day8.txt:3:5: expected a number, found '+x'"
+        );
+    }
+
+    #[test]
+    fn test_number() {
+        let input = "1,2,three";
+        let tokens: Vec<&str> = input.split(',').collect();
+        assert_eq!(number::<u64>(input, tokens[1]), Ok(2));
+        let err = number::<u64>(input, tokens[2]).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 5));
+        assert_eq!(err.token, "three");
+    }
+}
@@ -0,0 +1,41 @@
+//! Commands a day offers in `aoc repl` for poking at its parsed input, besides solving the parts.
+
+use std::str::FromStr;
+
+/// A day's session in the REPL, keeping whatever its commands change between them, like the
+/// program counter of day 8 or the cups day 23 has moved so far.
+pub trait Explore {
+    /// The commands with their arguments, and what they do.
+    fn commands(&self) -> &'static [(&'static str, &'static str)];
+
+    /// Runs `command` on the rest of the line, `args`, returning what to show. `None` means the day
+    /// has no such command.
+    fn run(&mut self, command: &str, args: &str) -> Option<Result<String, String>>;
+}
+
+pub type Explorer<'a> = Box<dyn Explore + 'a>;
+
+/// The argument of a command, or `default` when it was left out.
+pub fn arg<T: FromStr>(args: &str, default: T) -> Result<T, String> {
+    match args.trim() {
+        "" => Ok(default),
+        arg => arg
+            .parse()
+            .map_err(|_| format!("'{}' is not a valid argument", arg)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arg() {
+        assert_eq!(arg("", 1), Ok(1));
+        assert_eq!(arg(" 10 ", 1), Ok(10));
+        assert_eq!(
+            arg::<u32>("ten", 1),
+            Err("'ten' is not a valid argument".to_string())
+        );
+    }
+}
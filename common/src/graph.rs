@@ -0,0 +1,348 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Write,
+};
+
+/// A node of a [`Digraph`], numbered in the order the nodes were added.
+pub type NodeId = usize;
+
+/// A directed graph whose nodes are named by strings and whose edges carry a weight of type `W`.
+///
+/// Names are interned, so the algorithms work on cheap [`NodeId`]s and only [`Digraph::name`]
+/// goes back to the strings.
+#[derive(Debug, Clone)]
+pub struct Digraph<W = ()> {
+    names: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    edges: Vec<Vec<(NodeId, W)>>,
+    reverse: Vec<Vec<(NodeId, W)>>,
+}
+
+impl<W> Default for Digraph<W> {
+    fn default() -> Digraph<W> {
+        Digraph {
+            names: vec![],
+            ids: HashMap::new(),
+            edges: vec![],
+            reverse: vec![],
+        }
+    }
+}
+
+impl<W: Clone> Digraph<W> {
+    pub fn new() -> Digraph<W> {
+        Digraph::default()
+    }
+
+    /// The node called `name`, added to the graph if it isn't there yet.
+    pub fn node(&mut self, name: &str) -> NodeId {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+
+        let id = self.names.len();
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        self.edges.push(vec![]);
+        self.reverse.push(vec![]);
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        0..self.names.len()
+    }
+
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId, weight: W) {
+        self.edges[from].push((to, weight.clone()));
+        self.reverse[to].push((from, weight));
+    }
+
+    /// The edges leaving `id`, with their weights.
+    pub fn edges(&self, id: NodeId) -> &[(NodeId, W)] {
+        &self.edges[id]
+    }
+
+    /// The edges arriving at `id`, with the node they come from and their weights.
+    pub fn reverse_edges(&self, id: NodeId) -> &[(NodeId, W)] {
+        &self.reverse[id]
+    }
+
+    /// The same graph with every edge pointing the other way.
+    pub fn reversed(&self) -> Digraph<W> {
+        Digraph {
+            names: self.names.clone(),
+            ids: self.ids.clone(),
+            edges: self.reverse.clone(),
+            reverse: self.edges.clone(),
+        }
+    }
+
+    /// The nodes reachable from `start`, `start` first, in depth-first order.
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        let mut visited = vec![false; self.len()];
+        let mut order = vec![];
+        let mut stack = vec![start];
+
+        while let Some(id) = stack.pop() {
+            if visited[id] {
+                continue;
+            }
+            visited[id] = true;
+            order.push(id);
+            // reversed, so that the first edge is explored first
+            stack.extend(self.edges[id].iter().rev().map(|(to, _)| *to));
+        }
+
+        order
+    }
+
+    /// The nodes reachable from `start` with their distance in edges, nearest first.
+    pub fn bfs(&self, start: NodeId) -> Vec<(NodeId, usize)> {
+        let mut distance: Vec<Option<usize>> = vec![None; self.len()];
+        let mut order = vec![];
+        let mut queue = VecDeque::from(vec![start]);
+        distance[start] = Some(0);
+
+        while let Some(id) = queue.pop_front() {
+            let d = distance[id].unwrap();
+            order.push((id, d));
+            for (to, _) in &self.edges[id] {
+                if distance[*to].is_none() {
+                    distance[*to] = Some(d + 1);
+                    queue.push_back(*to);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Every node ordered so that edges only point forwards, or the nodes of a cycle that makes
+    /// that impossible.
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>, Vec<NodeId>> {
+        let mut order = vec![];
+        let mut state = vec![State::New; self.len()];
+        for id in self.nodes() {
+            self.post_order(id, &mut state, &mut order)?;
+        }
+
+        order.reverse();
+        Ok(order)
+    }
+
+    /// The nodes of some cycle, if the graph has one.
+    pub fn find_cycle(&self) -> Option<Vec<NodeId>> {
+        self.topological_sort().err()
+    }
+
+    /// Adds the nodes reachable from `start` to `order`, each after all its successors, or returns
+    /// a cycle found on the way.
+    fn post_order(
+        &self,
+        start: NodeId,
+        state: &mut [State],
+        order: &mut Vec<NodeId>,
+    ) -> Result<(), Vec<NodeId>> {
+        if state[start] != State::New {
+            return Ok(());
+        }
+
+        // the path from start, with the index of the next edge to follow from each node on it
+        let mut path: Vec<(NodeId, usize)> = vec![(start, 0)];
+        state[start] = State::OnPath;
+
+        while let Some((id, next)) = path.last_mut() {
+            let id = *id;
+            match self.edges[id].get(*next) {
+                Some((to, _)) => {
+                    *next += 1;
+                    match state[*to] {
+                        State::New => {
+                            state[*to] = State::OnPath;
+                            path.push((*to, 0));
+                        }
+                        State::OnPath => {
+                            let from = path.iter().position(|(n, _)| n == to).unwrap();
+                            return Err(path[from..].iter().map(|(n, _)| *n).collect());
+                        }
+                        State::Done => {}
+                    }
+                }
+                None => {
+                    state[id] = State::Done;
+                    order.push(id);
+                    path.pop();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Folds the graph below `start` into a value, computing each node's value from those of its
+    /// successors and the weights of the edges to them. Every node is computed once, however many
+    /// paths lead to it.
+    ///
+    /// Panics if there is a cycle below `start`.
+    pub fn fold<T: Clone>(&self, start: NodeId, mut f: impl FnMut(NodeId, &[(T, &W)]) -> T) -> T {
+        let mut order = vec![];
+        let mut state = vec![State::New; self.len()];
+        if let Err(cycle) = self.post_order(start, &mut state, &mut order) {
+            let names: Vec<&str> = cycle.iter().map(|id| self.name(*id)).collect();
+            panic!("Can't fold a graph with a cycle: {}", names.join(" -> "));
+        }
+
+        let mut values: Vec<Option<T>> = vec![None; self.len()];
+        for id in order {
+            let successors: Vec<(T, &W)> = self.edges[id]
+                .iter()
+                .map(|(to, weight)| (values[*to].clone().unwrap(), weight))
+                .collect();
+            values[id] = Some(f(id, &successors));
+        }
+
+        values[start].take().unwrap()
+    }
+
+    /// The graph in Graphviz's DOT language, labelling each edge with `label` of its weight.
+    pub fn to_dot(&self, label: impl Fn(&W) -> Option<String>) -> String {
+        let quote = |s: &str| format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""));
+
+        let mut dot = String::from("digraph {\n");
+        for id in self.nodes() {
+            writeln!(dot, "    {};", quote(self.name(id))).unwrap();
+        }
+        for from in self.nodes() {
+            for (to, weight) in &self.edges[from] {
+                write!(
+                    dot,
+                    "    {} -> {}",
+                    quote(self.name(from)),
+                    quote(self.name(*to))
+                )
+                .unwrap();
+                if let Some(label) = label(weight) {
+                    write!(dot, " [label={}]", quote(&label)).unwrap();
+                }
+                dot.push_str(";\n");
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    New,
+    OnPath,
+    Done,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -> b -> d, a -> c -> d, with weights 1 to 4
+    fn diamond() -> Digraph<u64> {
+        let mut graph = Digraph::new();
+        let (a, b, c, d) = (
+            graph.node("a"),
+            graph.node("b"),
+            graph.node("c"),
+            graph.node("d"),
+        );
+        graph.add_edge(a, b, 1);
+        graph.add_edge(a, c, 2);
+        graph.add_edge(b, d, 3);
+        graph.add_edge(c, d, 4);
+        graph
+    }
+
+    fn names(graph: &Digraph<u64>, ids: &[NodeId]) -> Vec<String> {
+        ids.iter().map(|id| graph.name(*id).to_string()).collect()
+    }
+
+    #[test]
+    fn test_interning() {
+        let mut graph = diamond();
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.node("c"), 2);
+        assert_eq!(graph.id("d"), Some(3));
+        assert_eq!(graph.id("e"), None);
+        assert_eq!(graph.reverse_edges(3), &[(1, 3), (2, 4)]);
+    }
+
+    #[test]
+    fn test_traversal() {
+        let graph = diamond();
+        assert_eq!(names(&graph, &graph.dfs(0)), vec!["a", "b", "d", "c"]);
+        assert_eq!(graph.bfs(0), vec![(0, 0), (1, 1), (2, 1), (3, 2)]);
+        assert_eq!(
+            names(&graph, &graph.reversed().dfs(3)),
+            vec!["d", "b", "a", "c"]
+        );
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let mut graph = diamond();
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(names(&graph, &order), vec!["a", "c", "b", "d"]);
+        assert_eq!(graph.find_cycle(), None);
+
+        let (d, a) = (graph.node("d"), graph.node("a"));
+        graph.add_edge(d, a, 5);
+        let cycle = graph.find_cycle().unwrap();
+        assert_eq!(names(&graph, &cycle), vec!["a", "b", "d"]);
+    }
+
+    #[test]
+    fn test_fold() {
+        let graph = diamond();
+        // the number of paths to d, and the longest path by weight
+        let paths = graph.fold(0, |_, successors| {
+            if successors.is_empty() {
+                1
+            } else {
+                successors.iter().map(|(paths, _)| paths).sum()
+            }
+        });
+        assert_eq!(paths, 2);
+        let longest = graph.fold(0, |_, successors| {
+            successors
+                .iter()
+                .map(|(len, w)| len + *w)
+                .max()
+                .unwrap_or(0)
+        });
+        assert_eq!(longest, 6);
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut graph = Digraph::new();
+        let (a, b) = (graph.node("light red"), graph.node("say \"hi\""));
+        graph.add_edge(a, b, 2);
+        assert_eq!(
+            graph.to_dot(|w| Some(w.to_string())),
+            "digraph {\n    \"light red\";\n    \"say \\\"hi\\\"\";\n    \"light red\" -> \"say \\\"hi\\\"\" [label=\"2\"];\n}\n"
+        );
+    }
+}
@@ -0,0 +1,415 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::ParseError;
+
+/// Offsets to the 4 orthogonal neighbours, as `(dx, dy)`.
+pub const NEIGHBOURS4: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to the 8 neighbours including diagonals, as `(dx, dy)`.
+pub const NEIGHBOURS8: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// A rectangular grid indexed by `(x, y)`, with `(0, 0)` in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from rows that all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, |row| row.len());
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "all rows must be {} wide",
+            width
+        );
+
+        Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses one cell per character and one row per line of `text`, a slice of `input`. `cell`
+    /// returns `None` for characters that are not allowed, which are reported as not `expected`.
+    pub fn parse(
+        input: &str,
+        text: &str,
+        cell: impl Fn(char) -> Option<T>,
+        expected: &str,
+    ) -> Result<Grid<T>, ParseError> {
+        let lines: Vec<&str> = text.lines().collect();
+        let width = match lines.first() {
+            Some(line) => line.chars().count(),
+            None => return Err(ParseError::new(input, text, "a grid")),
+        };
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for line in &lines {
+            if line.chars().count() != width {
+                let expected = format!("a row of {} squares", width);
+                return Err(ParseError::new(input, line, expected));
+            }
+            for (i, chr) in line.char_indices() {
+                match cell(chr) {
+                    Some(cell) => cells.push(cell),
+                    None => {
+                        let token = &line[i..i + chr.len_utf8()];
+                        return Err(ParseError::new(input, token, expected));
+                    }
+                }
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `(x, y)`, or `None` outside the grid.
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        self.position(x, y).map(|(x, y)| &self[(x, y)])
+    }
+
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        self.position(x, y).map(move |(x, y)| &mut self[(x, y)])
+    }
+
+    /// The cell at `(x, y)` on a grid that repeats forever in every direction.
+    pub fn get_wrapping(&self, x: i64, y: i64) -> &T {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        &self[(x, y)]
+    }
+
+    fn position(&self, x: i64, y: i64) -> Option<(usize, usize)> {
+        if x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, and a grid without columns has no rows worth iterating
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// All cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// The positions at `offsets` from `(x, y)` that are inside the grid.
+    fn offsets<'a>(
+        &self,
+        (x, y): (usize, usize),
+        offsets: &'a [(i64, i64)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let (width, height) = (self.width as i64, self.height as i64);
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let (nx, ny) = (x as i64 + dx, y as i64 + dy);
+            if nx >= 0 && ny >= 0 && nx < width && ny < height {
+                Some((nx as usize, ny as usize))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The orthogonal neighbours of `pos` inside the grid.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(pos, &NEIGHBOURS4)
+    }
+
+    /// The neighbours of `pos` inside the grid, diagonals included.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+        self.offsets(pos, &NEIGHBOURS8)
+    }
+
+    /// The positions seen looking from `(x, y)` in direction `(dx, dy)`, up to the edge of the
+    /// grid. `(x, y)` itself is not included.
+    pub fn ray(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (i64, i64),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let start = (x as i64, y as i64);
+        std::iter::successors(Some(start), move |(x, y)| Some((x + dx, y + dy)))
+            .skip(1)
+            .map_while(move |(x, y)| self.position(x, y))
+    }
+
+    /// A new grid with `f` applied to every position and cell.
+    pub fn map<U>(&self, mut f: impl FnMut((usize, usize), &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self
+                .positions()
+                .zip(self.cells.iter())
+                .map(|(pos, cell)| f(pos, cell))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// The grid turned a quarter clockwise.
+    pub fn rotate(&self) -> Grid<T> {
+        let mut cells = Vec::with_capacity(self.cells.len());
+        for x in 0..self.width {
+            for y in (0..self.height).rev() {
+                cells.push(self[(x, y)].clone());
+            }
+        }
+
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// The grid mirrored left to right.
+    pub fn flip(&self) -> Grid<T> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self
+                .rows()
+                .flat_map(|row| row.iter().rev().cloned())
+                .collect(),
+        }
+    }
+
+    /// All eight ways to rotate and flip the grid, starting with the grid itself: each quarter
+    /// turn, followed by its mirror image.
+    pub fn orientations(&self) -> Vec<Grid<T>> {
+        let mut orientations = Vec::with_capacity(8);
+        let mut grid = self.clone();
+        for _ in 0..4 {
+            let (flipped, rotated) = (grid.flip(), grid.rotate());
+            orientations.push(grid);
+            orientations.push(flipped);
+            grid = rotated;
+        }
+        orientations
+    }
+
+    /// The `width` by `height` part of the grid with its top left corner at `(x, y)`.
+    pub fn sub_grid(&self, (x, y): (usize, usize), width: usize, height: usize) -> Grid<T> {
+        assert!(x + width <= self.width && y + height <= self.height);
+
+        Grid {
+            width,
+            height,
+            cells: (y..y + height)
+                .flat_map(|y| self.row(y)[x..x + width].iter().cloned())
+                .collect(),
+        }
+    }
+
+    /// Copies `other` into this grid with its top left corner at `(x, y)`.
+    pub fn paste(&mut self, (x, y): (usize, usize), other: &Grid<T>) {
+        for (ox, oy) in other.positions() {
+            self[(x + ox, y + oy)] = other[(ox, oy)].clone();
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width, "x {} is outside the grid", x);
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width, "x {} is outside the grid", x);
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+/// One line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::parse(text, text, Some, "anything").unwrap()
+    }
+
+    /// Grids of up to 12 by 12 small numbers, so that cells repeat now and then.
+    fn grids() -> impl Strategy<Value = Grid<u8>> {
+        (1..=12usize, 1..=12usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(0..4u8, width * height).prop_map(move |cells| {
+                Grid::from_rows(cells.chunks(width).map(Vec::from).collect())
+            })
+        })
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = "#.#\n..#";
+        let g = Grid::parse(input, input, |c| Some(c == '#'), "'.' or '#'").unwrap();
+        assert_eq!((g.width(), g.height()), (3, 2));
+        assert!(g[(2, 1)]);
+        assert_eq!(g.get(3, 1), None);
+        assert_eq!(g.get(-1, 0), None);
+        assert_eq!(g.get_wrapping(5, -1), &true);
+
+        let input = "#.#\n..";
+        let err = Grid::parse(input, input, |c| Some(c == '#'), "'.' or '#'").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 1, "a row of 3 squares")
+        );
+
+        let input = "#.#\n.x#";
+        let err = Grid::parse(input, input, |c| "#.".find(c), "'.' or '#'").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "x"));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(grid("123\n456").to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let g = grid("123\n456\n789");
+        let cells = |positions: Vec<(usize, usize)>| -> String {
+            positions.into_iter().map(|pos| g[pos]).collect()
+        };
+        assert_eq!(cells(g.neighbours4((1, 1)).collect()), "2684");
+        assert_eq!(cells(g.neighbours8((1, 1)).collect()), "12369874");
+        assert_eq!(cells(g.neighbours8((0, 0)).collect()), "254");
+        assert_eq!(cells(g.ray((0, 0), (1, 1)).collect()), "59");
+        assert_eq!(cells(g.ray((2, 1), (1, 0)).collect()), "");
+    }
+
+    #[test]
+    fn test_transforms() {
+        let g = grid("123\n456\n789");
+        assert_eq!(g.rotate(), grid("741\n852\n963"));
+        assert_eq!(g.rotate().rotate(), grid("987\n654\n321"));
+        assert_eq!(g.rotate().rotate().rotate(), grid("369\n258\n147"));
+        assert_eq!(g.flip(), grid("321\n654\n987"));
+        assert_eq!(g.rotate().flip(), grid("147\n258\n369"));
+
+        let orientations = g.orientations();
+        assert_eq!(orientations.len(), 8);
+        assert_eq!(orientations[0], g);
+        assert_eq!(orientations[1], g.flip());
+        assert_eq!(orientations[2], g.rotate());
+        assert_eq!(orientations[7], g.rotate().rotate().rotate().flip());
+
+        let g = grid("123\n456");
+        assert_eq!(g.rotate(), grid("41\n52\n63"));
+    }
+
+    #[test]
+    fn test_sub_grid() {
+        let mut g = grid("123\n456\n789");
+        assert_eq!(g.sub_grid((1, 1), 2, 2), grid("56\n89"));
+        assert_eq!(g.column(1).collect::<String>(), "258");
+
+        g.paste((0, 1), &grid("ab"));
+        assert_eq!(g, grid("123\nab6\n789"));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_rotate_four_times(g in grids()) {
+            let once = g.rotate();
+            prop_assert_eq!((once.width(), once.height()), (g.height(), g.width()));
+            prop_assert_eq!(once.rotate().rotate().rotate(), g);
+        }
+
+        #[test]
+        fn prop_flip_twice(g in grids()) {
+            prop_assert_eq!(g.flip().flip(), g.clone());
+            // a mirrored quarter turn is a mirror too
+            prop_assert_eq!(g.rotate().flip().rotate().flip(), g);
+        }
+
+        #[test]
+        fn prop_orientations(g in grids()) {
+            let mut cells: Vec<u8> = g.iter().copied().collect();
+            cells.sort_unstable();
+            for orientation in g.orientations() {
+                let mut moved: Vec<u8> = orientation.iter().copied().collect();
+                moved.sort_unstable();
+                prop_assert_eq!(&moved, &cells);
+                prop_assert!(orientation.orientations().contains(&g));
+            }
+        }
+    }
+}
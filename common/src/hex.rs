@@ -0,0 +1,309 @@
+//! Hexagonal grids, after https://www.redblobgames.com/grids/hexagons/.
+//!
+//! Positions are [`Axial`] coordinates: `q` grows towards the east (or south east, with flat tops)
+//! and `r` towards the south east (or south, with flat tops), and the third cube coordinate is
+//! implied. [`Cube`] and the offset systems of [`Offset`] convert to and from them.
+
+use std::ops::{Add, Mul, Sub};
+
+use crate::{ParseError, Space};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Axial {
+    pub q: i64,
+    pub r: i64,
+}
+
+/// The same position as an [`Axial`], with `q + r + s == 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Cube {
+    pub q: i64,
+    pub r: i64,
+    pub s: i64,
+}
+
+/// The ways of storing a hex grid in rows and columns, shoving every odd or even row (for pointy
+/// tops) or column (for flat tops) half a hex over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Offset {
+    OddR,
+    EvenR,
+    OddQ,
+    EvenQ,
+}
+
+/// Whether the hexes have a corner or an edge at the top, which decides the directions they have
+/// neighbours in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    PointyTop,
+    FlatTop,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    East,
+    West,
+    North,
+    South,
+    Northeast,
+    Northwest,
+    Southeast,
+    Southwest,
+}
+
+/// The six neighbours, clockwise from east on pointy tops and from south east on flat tops.
+const OFFSETS: [Axial; 6] = [
+    Axial::new(1, 0),
+    Axial::new(0, 1),
+    Axial::new(-1, 1),
+    Axial::new(-1, 0),
+    Axial::new(0, -1),
+    Axial::new(1, -1),
+];
+
+impl Axial {
+    pub const fn new(q: i64, r: i64) -> Axial {
+        Axial { q, r }
+    }
+
+    pub fn neighbours(self) -> impl Iterator<Item = Axial> {
+        OFFSETS.iter().map(move |offset| self + *offset)
+    }
+
+    /// The number of steps between two hexes.
+    pub fn distance(self, other: Axial) -> i64 {
+        let d = Cube::from(self - other);
+        (d.q.abs() + d.r.abs() + d.s.abs()) / 2
+    }
+
+    /// The hexes exactly `radius` steps away, going around once.
+    pub fn ring(self, radius: i64) -> Vec<Axial> {
+        if radius == 0 {
+            return vec![self];
+        }
+
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        let mut hex = self + OFFSETS[4] * radius;
+        for offset in OFFSETS.iter() {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex = hex + *offset;
+            }
+        }
+        ring
+    }
+
+    /// The hexes at most `radius` steps away.
+    pub fn range(self, radius: i64) -> Vec<Axial> {
+        let mut range = vec![];
+        for q in -radius..=radius {
+            for r in (-radius).max(-q - radius)..=radius.min(-q + radius) {
+                range.push(self + Axial::new(q, r));
+            }
+        }
+        range
+    }
+
+    /// The `(column, row)` of this hex in the `offset` system.
+    pub fn to_offset(self, offset: Offset) -> (i64, i64) {
+        let Axial { q, r } = self;
+        match offset {
+            Offset::OddR => (q + (r - (r & 1)) / 2, r),
+            Offset::EvenR => (q + (r + (r & 1)) / 2, r),
+            Offset::OddQ => (q, r + (q - (q & 1)) / 2),
+            Offset::EvenQ => (q, r + (q + (q & 1)) / 2),
+        }
+    }
+
+    pub fn from_offset(offset: Offset, (col, row): (i64, i64)) -> Axial {
+        match offset {
+            Offset::OddR => Axial::new(col - (row - (row & 1)) / 2, row),
+            Offset::EvenR => Axial::new(col - (row + (row & 1)) / 2, row),
+            Offset::OddQ => Axial::new(col, row - (col - (col & 1)) / 2),
+            Offset::EvenQ => Axial::new(col, row - (col + (col & 1)) / 2),
+        }
+    }
+}
+
+impl Add for Axial {
+    type Output = Axial;
+
+    fn add(self, other: Axial) -> Axial {
+        Axial::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Axial {
+    type Output = Axial;
+
+    fn sub(self, other: Axial) -> Axial {
+        Axial::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<i64> for Axial {
+    type Output = Axial;
+
+    fn mul(self, factor: i64) -> Axial {
+        Axial::new(self.q * factor, self.r * factor)
+    }
+}
+
+impl From<Axial> for Cube {
+    fn from(hex: Axial) -> Cube {
+        Cube {
+            q: hex.q,
+            r: hex.r,
+            s: -hex.q - hex.r,
+        }
+    }
+}
+
+impl From<Cube> for Axial {
+    fn from(cube: Cube) -> Axial {
+        Axial::new(cube.q, cube.r)
+    }
+}
+
+impl Layout {
+    /// The six directions hexes have neighbours in, in the same order as
+    /// [`Axial::neighbours`].
+    pub fn directions(self) -> [Direction; 6] {
+        use Direction::*;
+        match self {
+            Layout::PointyTop => [East, Southeast, Southwest, West, Northwest, Northeast],
+            Layout::FlatTop => [Southeast, South, Southwest, Northwest, North, Northeast],
+        }
+    }
+
+    /// The step in direction `dir`, if hexes with this layout have a neighbour that way.
+    pub fn step(self, dir: Direction) -> Option<Axial> {
+        let i = self.directions().iter().position(|d| *d == dir)?;
+        Some(OFFSETS[i])
+    }
+
+    /// Parses a path of directions written without separators, like `esenee` or `nwswn`, where
+    /// `line` is a slice of `input`.
+    pub fn parse_path(self, input: &str, line: &str) -> Result<Vec<Direction>, ParseError> {
+        let mut path = vec![];
+        let mut rest = line;
+
+        while !rest.is_empty() {
+            let len = match (self, rest.as_bytes()) {
+                (Layout::PointyTop, [b'n', ..]) | (Layout::PointyTop, [b's', ..]) => 2,
+                (Layout::FlatTop, [b'n', b'e', ..])
+                | (Layout::FlatTop, [b'n', b'w', ..])
+                | (Layout::FlatTop, [b's', b'e', ..])
+                | (Layout::FlatTop, [b's', b'w', ..]) => 2,
+                _ => 1,
+            };
+            let token = rest.get(..len).unwrap_or(rest);
+            let dir = Direction::from_abbreviation(token)
+                .filter(|dir| self.step(*dir).is_some())
+                .ok_or_else(|| ParseError::new(input, token, self.expected()))?;
+            path.push(dir);
+            rest = &rest[token.len()..];
+        }
+
+        Ok(path)
+    }
+
+    fn expected(self) -> &'static str {
+        match self {
+            Layout::PointyTop => "a direction of 'e', 'se', 'sw', 'w', 'nw' or 'ne'",
+            Layout::FlatTop => "a direction of 'se', 's', 'sw', 'nw', 'n' or 'ne'",
+        }
+    }
+}
+
+impl Direction {
+    fn from_abbreviation(s: &str) -> Option<Direction> {
+        Some(match s {
+            "e" => Direction::East,
+            "w" => Direction::West,
+            "n" => Direction::North,
+            "s" => Direction::South,
+            "ne" => Direction::Northeast,
+            "nw" => Direction::Northwest,
+            "se" => Direction::Southeast,
+            "sw" => Direction::Southwest,
+            _ => return None,
+        })
+    }
+}
+
+/// The infinite plane of hexes, for automata.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Plane;
+
+impl Space for Plane {
+    type Cell = Axial;
+
+    fn neighbours(&self, hex: &Axial) -> Vec<Axial> {
+        hex.neighbours().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        let origin = Axial::default();
+        assert_eq!(origin.distance(Axial::new(3, 0)), 3);
+        assert_eq!(origin.distance(Axial::new(2, -3)), 3);
+        assert_eq!(Axial::new(-1, 2).distance(Axial::new(2, -1)), 3);
+        assert!(origin.neighbours().all(|n| origin.distance(n) == 1));
+        assert_eq!(Cube::from(Axial::new(2, -3)), Cube { q: 2, r: -3, s: 1 });
+    }
+
+    #[test]
+    fn test_ring_and_range() {
+        let center = Axial::new(1, 2);
+        assert_eq!(center.ring(0), vec![center]);
+        for radius in 1..4 {
+            let ring = center.ring(radius);
+            assert_eq!(ring.len(), 6 * radius as usize);
+            assert!(ring.iter().all(|hex| center.distance(*hex) == radius));
+            let range = center.range(radius);
+            assert_eq!(range.len(), (3 * radius * (radius + 1) + 1) as usize);
+            assert!(range.iter().all(|hex| center.distance(*hex) <= radius));
+        }
+    }
+
+    #[test]
+    fn test_offset() {
+        for offset in [Offset::OddR, Offset::EvenR, Offset::OddQ, Offset::EvenQ] {
+            for hex in Axial::default().range(3) {
+                assert_eq!(Axial::from_offset(offset, hex.to_offset(offset)), hex);
+            }
+        }
+        assert_eq!(Axial::new(-1, 1).to_offset(Offset::OddR), (-1, 1));
+        assert_eq!(Axial::new(-1, 1).to_offset(Offset::EvenR), (0, 1));
+        assert_eq!(Axial::new(1, -1).to_offset(Offset::OddQ), (1, -1));
+        assert_eq!(Axial::new(1, -1).to_offset(Offset::EvenQ), (1, 0));
+    }
+
+    #[test]
+    fn test_parse_path() {
+        use Direction::*;
+
+        let path = Layout::PointyTop.parse_path("nwwswee", "nwwswee").unwrap();
+        assert_eq!(path, vec![Northwest, West, Southwest, East, East]);
+        let end = path.iter().fold(Axial::default(), |hex, dir| {
+            hex + Layout::PointyTop.step(*dir).unwrap()
+        });
+        assert_eq!(end, Axial::default());
+
+        let path = Layout::FlatTop.parse_path("nsnesw", "nsnesw").unwrap();
+        assert_eq!(path, vec![North, South, Northeast, Southwest]);
+
+        let err = Layout::PointyTop.parse_path("nes", "nes").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (3, "s"));
+        let err = Layout::FlatTop.parse_path("nsx", "nsx").unwrap_err();
+        assert_eq!((err.column, err.token.as_str()), (3, "x"));
+        assert!(Layout::FlatTop.parse_path("e", "e").is_err());
+    }
+}
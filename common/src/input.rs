@@ -0,0 +1,126 @@
+use crate::error::{number, ParseError};
+
+use std::{
+    fs::{self, File},
+    io::{self, prelude::*, BufReader},
+    path::Path,
+};
+
+fn open(filename: &Path) -> File {
+    File::open(filename).unwrap_or_else(|e| panic!("Could not open {}: {}", filename.display(), e))
+}
+
+/// Reads the whole file into a string, as is.
+pub fn string_from_file(filename: impl AsRef<Path>) -> String {
+    let filename = filename.as_ref();
+    fs::read_to_string(filename)
+        .unwrap_or_else(|e| panic!("Could not read {}: {}", filename.display(), e))
+}
+
+/// Reads everything from `reader`, e.g. stdin, into a string.
+pub fn string_from_reader(mut reader: impl Read) -> io::Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+/// Reads all of stdin into a string.
+pub fn string_from_stdin() -> String {
+    string_from_reader(io::stdin().lock()).unwrap_or_else(|e| panic!("Could not read stdin: {}", e))
+}
+
+/// Reads the file line by line, without line endings.
+pub fn lines_from_file(filename: impl AsRef<Path>) -> Vec<String> {
+    let buf = BufReader::new(open(filename.as_ref()));
+    buf.lines()
+        .map(|l| l.expect("Could not parse line"))
+        .collect()
+}
+
+/// Parses one integer per line.
+pub fn ints(input: &str) -> Result<Vec<i64>, ParseError> {
+    input.lines().map(|l| number(input, l.trim())).collect()
+}
+
+/// Reads one integer per line.
+pub fn ints_from_file(filename: impl AsRef<Path>) -> Vec<i64> {
+    let filename = filename.as_ref();
+    ints(&string_from_file(filename))
+        .unwrap_or_else(|e| panic!("{}", e.in_file(filename.display().to_string())))
+}
+
+/// Splits input into blocks of lines separated by blank lines, e.g. passports or answer groups.
+pub fn groups(input: &str) -> Vec<Vec<&str>> {
+    let mut groups = vec![];
+    let mut group = vec![];
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            if !group.is_empty() {
+                groups.push(group);
+                group = vec![];
+            }
+        } else {
+            group.push(line);
+        }
+    }
+    // don't forget the last one
+    if !group.is_empty() {
+        groups.push(group);
+    }
+
+    groups
+}
+
+/// Reads blocks of lines separated by blank lines.
+pub fn groups_from_file(filename: impl AsRef<Path>) -> Vec<Vec<String>> {
+    groups(&string_from_file(filename))
+        .iter()
+        .map(|group| group.iter().map(|l| l.to_string()).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_tmp(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("aoc-common-{}-{}", std::process::id(), name));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_lines_and_ints() {
+        let path = write_tmp("ints.txt", "1\n-2\n 3\n");
+        assert_eq!(lines_from_file(&path), vec!["1", "-2", " 3"]);
+        assert_eq!(ints_from_file(&path), vec![1, -2, 3]);
+        assert_eq!(string_from_file(&path), "1\n-2\n 3\n");
+    }
+
+    #[test]
+    fn test_string_from_reader() {
+        let input = string_from_reader(io::Cursor::new("1\n2\n")).unwrap();
+        assert_eq!(ints(&input), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn test_groups() {
+        let path = write_tmp("groups.txt", "abc\n\na\nb\n\n\nc\n");
+        assert_eq!(
+            groups_from_file(&path),
+            vec![vec!["abc"], vec!["a", "b"], vec!["c"]]
+        );
+    }
+
+    #[test]
+    fn test_ints_error() {
+        let err = ints("1\n 2x\n3").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "2x"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Could not open")]
+    fn test_missing_file() {
+        lines_from_file("this/file/does/not/exist.txt");
+    }
+}
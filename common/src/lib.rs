@@ -0,0 +1,26 @@
+pub mod assign;
+pub mod automaton;
+pub mod error;
+pub mod explore;
+pub mod graph;
+pub mod grid;
+pub mod hex;
+pub mod input;
+pub mod math;
+pub mod params;
+pub mod render;
+pub mod solution;
+
+pub use assign::{assign, AssignError};
+pub use automaton::{Automaton, Lattice, Life, Rule, Space};
+pub use error::{captures, number, ParseError};
+pub use explore::{Explore, Explorer};
+pub use graph::{Digraph, NodeId};
+pub use grid::{Grid, NEIGHBOURS4, NEIGHBOURS8};
+pub use input::{
+    groups, groups_from_file, ints, ints_from_file, lines_from_file, string_from_file,
+    string_from_reader, string_from_stdin,
+};
+pub use params::Params;
+pub use render::{Frame, Frames};
+pub use solution::{solve, Answer, Parsed, Solution, Solver};
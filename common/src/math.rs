@@ -0,0 +1,366 @@
+//! Number theory for the puzzles that are secretly about modular arithmetic.
+//!
+//! Products go through `i128`, so any modulus that fits in an `i64` works without overflowing.
+
+use std::{collections::HashMap, convert::TryFrom};
+
+/// `a * b mod m`, in `0..m`.
+pub fn mulmod(a: i64, b: i64, m: i64) -> i64 {
+    (a as i128 * b as i128).rem_euclid(m as i128) as i64
+}
+
+/// `base^exp mod m`, in `0..m`.
+pub fn powmod(base: i64, mut exp: u64, m: i64) -> i64 {
+    let mut base = base.rem_euclid(m);
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mulmod(result, base, m);
+        }
+        base = mulmod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        (a / gcd(a, b) * b).abs()
+    }
+}
+
+/// `(g, x, y)` such that `a * x + b * y == g == gcd(a, b)`.
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `a` and `m` are coprime.
+pub fn mod_inv(a: i64, m: i64) -> Option<i64> {
+    let (g, x, _) = egcd(a.rem_euclid(m), m);
+    if g == 1 {
+        Some(x.rem_euclid(m))
+    } else {
+        None
+    }
+}
+
+/// Solves the system `x ≡ residue (mod modulus)` for every `(residue, modulus)`, returning the
+/// solution as `(x, lcm of the moduli)` with `x` in `0..lcm`. The moduli need not be coprime, but
+/// then the residues have to agree, or there is no solution. There is none either when the lcm
+/// doesn't fit in an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let mut x: i128 = 0;
+    let mut m: i128 = 1;
+
+    for &(residue, modulus) in congruences {
+        let (residue, modulus) = (residue as i128, modulus as i128);
+        let g = gcd(m as i64, modulus as i64) as i128;
+        let diff = residue - x;
+        if diff % g != 0 {
+            return None;
+        }
+
+        // x + m * k ≡ residue (mod modulus), so k ≡ diff / g * (m / g)^-1 (mod modulus / g)
+        let step = modulus / g;
+        let inv = mod_inv((m / g % step) as i64, step as i64)? as i128;
+        let k = (diff / g).rem_euclid(step) * inv % step;
+        x += m * k;
+        // m stays within an i64, so the conversions above and below are exact
+        m = i64::try_from(m * step).ok()? as i128;
+        x = x.rem_euclid(m);
+    }
+
+    Some((x as i64, m as i64))
+}
+
+/// The smallest `x` with `base^x ≡ target (mod m)`, found with baby-step giant-step in about
+/// `sqrt(m)` steps. `base` has to be coprime to `m`.
+pub fn discrete_log(base: i64, target: i64, m: i64) -> Option<u64> {
+    let target = target.rem_euclid(m);
+    let n = (m as f64).sqrt().ceil() as i64;
+
+    // baby steps: base^j for j < n, keeping the smallest j for each value
+    let mut table = HashMap::with_capacity(n as usize);
+    let mut value = 1 % m;
+    for j in 0..n {
+        table.entry(value).or_insert(j);
+        value = mulmod(value, base, m);
+    }
+
+    // giant steps: target * base^(-n*i) for i < n
+    let factor = mod_inv(powmod(base, n as u64, m), m)?;
+    let mut gamma = target;
+    for i in 0..n {
+        if let Some(j) = table.get(&gamma) {
+            return Some((i * n + j) as u64);
+        }
+        gamma = mulmod(gamma, factor, m);
+    }
+
+    None
+}
+
+/// The primes below `limit`, by the sieve of Eratosthenes.
+pub fn primes(limit: usize) -> Vec<u64> {
+    let mut composite = vec![false; limit];
+    let mut primes = vec![];
+    for n in 2..limit {
+        if !composite[n] {
+            primes.push(n as u64);
+            for multiple in (n * n..limit).step_by(n) {
+                composite[multiple] = true;
+            }
+        }
+    }
+    primes
+}
+
+fn mulmod_u64(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn powmod_u64(mut base: u64, mut exp: u64, m: u64) -> u64 {
+    let mut result = 1;
+    base %= m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mulmod_u64(result, base, m);
+        }
+        base = mulmod_u64(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Whether `n` is prime, by a Miller-Rabin test with bases that make it exact for all `u64`.
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    for p in BASES.iter() {
+        if n.is_multiple_of(*p) {
+            return n == *p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    BASES.iter().all(|&a| {
+        let mut x = powmod_u64(a, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+        for _ in 1..s {
+            x = mulmod_u64(x, x, n);
+            if x == n - 1 {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// Some non-trivial factor of the odd composite `n`, by Pollard's rho.
+fn pollard_rho(n: u64) -> u64 {
+    for c in 1.. {
+        let f = |x: u64| ((mulmod_u64(x, x, n) as u128 + c) % n as u128) as u64;
+        let (mut x, mut y, mut d) = (2, 2, 1);
+        while d == 1 {
+            x = f(x);
+            y = f(f(y));
+            d = gcd_u64(x.abs_diff(y), n);
+        }
+        if d != n {
+            return d;
+        }
+    }
+    unreachable!()
+}
+
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    a
+}
+
+/// The prime factors of `n` with their exponents, smallest first.
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    let mut factors = vec![];
+    let mut stack = vec![n];
+    while let Some(mut n) = stack.pop() {
+        // small factors are quicker by trial division
+        for p in [2, 3, 5, 7, 11, 13] {
+            while n.is_multiple_of(p) {
+                factors.push(p);
+                n /= p;
+            }
+        }
+        if n == 1 {
+            continue;
+        }
+        if is_prime(n) {
+            factors.push(n);
+        } else {
+            let d = pollard_rho(n);
+            stack.push(d);
+            stack.push(n / d);
+        }
+    }
+
+    factors.sort_unstable();
+    let mut counted: Vec<(u64, u32)> = vec![];
+    for p in factors {
+        match counted.last_mut() {
+            Some((q, count)) if *q == p => *count += 1,
+            _ => counted.push((p, 1)),
+        }
+    }
+    counted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(mulmod(i64::MAX - 1, i64::MAX - 2, i64::MAX), 2);
+        assert_eq!(mulmod(-3, 4, 5), 3);
+        assert_eq!(powmod(7, 8, 20201227), 5764801);
+        assert_eq!(powmod(2, 0, 1), 0);
+        assert_eq!(powmod(3, 200, 1_000_000_007), 136_318_165);
+        assert_eq!(egcd(240, 46), (2, -9, 47));
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(4, 8), None);
+        assert_eq!((gcd(12, -18), lcm(4, 6)), (6, 12));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(0, 17), (11, 13), (16, 19)]), Some((3417, 4199)));
+        assert_eq!(
+            crt(&[(0, 67), (6, 7), (57, 59), (58, 61)]),
+            Some((754018, 1687931))
+        );
+        // not coprime, but consistent
+        assert_eq!(crt(&[(2, 6), (8, 10)]), Some((8, 30)));
+        assert_eq!(crt(&[(1, 6), (2, 4)]), None);
+        assert_eq!(crt(&[]), Some((0, 1)));
+
+        // the lcm only just fits in an i64, and then not any more
+        let (a, b, c) = (3_037_000_489, 3_037_000_493, 3_037_000_507);
+        assert_eq!(
+            crt(&[(1, b), (2, a)]),
+            Some((6_917_528_987_509_930_932, 9_223_371_982_334_241_077))
+        );
+        assert_eq!(crt(&[(1, b), (2, c)]), None);
+        assert_eq!(crt(&[(1, b), (1, b), (2, c)]), None);
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        assert_eq!(discrete_log(7, 5764801, 20201227), Some(8));
+        assert_eq!(discrete_log(7, 17807724, 20201227), Some(11));
+        assert_eq!(discrete_log(2, 1, 11), Some(0));
+        // 2 only generates the squares mod 7
+        assert_eq!(discrete_log(2, 3, 7), None);
+    }
+
+    #[test]
+    fn test_primes() {
+        assert_eq!(primes(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert!(primes(10_000).iter().all(|p| is_prime(*p)));
+        assert_eq!((0..10_000).filter(|n| is_prime(*n)).count(), 1229);
+        assert!(is_prime(20201227));
+        assert!(is_prime(18_446_744_073_709_551_557));
+        assert!(!is_prime(3_215_031_751));
+    }
+
+    #[test]
+    fn test_factorize() {
+        assert_eq!(factorize(1), vec![]);
+        assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(
+            factorize(20201226),
+            vec![(2, 1), (3, 1), (29, 1), (116099, 1)]
+        );
+        assert_eq!(
+            factorize(600_851_475_143),
+            vec![(71, 1), (839, 1), (1471, 1), (6857, 1)]
+        );
+        assert_eq!(
+            factorize(1_000_000_007 * 998_244_353),
+            vec![(998_244_353, 1), (1_000_000_007, 1)]
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_mulmod(a: i64, b: i64, m in 1..=i64::MAX) {
+            let product = mulmod(a, b, m);
+            prop_assert!((0..m).contains(&product));
+            prop_assert_eq!(product as i128, (a as i128 * b as i128).rem_euclid(m as i128));
+        }
+
+        #[test]
+        fn prop_powmod(base: i64, e1 in 0..1u64 << 40, e2 in 0..1u64 << 40, m in 1..=i64::MAX) {
+            prop_assert_eq!(
+                powmod(base, e1 + e2, m),
+                mulmod(powmod(base, e1, m), powmod(base, e2, m), m)
+            );
+        }
+
+        #[test]
+        fn prop_crt(x in 0..1i64 << 50, moduli in prop::collection::vec(1..1000i64, 0..6)) {
+            // residues off by a few moduli, which the solution has to reduce
+            let congruences: Vec<(i64, i64)> =
+                moduli.iter().map(|m| (x % m - 3 * m, *m)).collect();
+            let (solution, lcm) = crt(&congruences).unwrap();
+            prop_assert_eq!(lcm, moduli.iter().fold(1, |l, m| super::lcm(l, *m)));
+            prop_assert!((0..lcm).contains(&solution));
+            prop_assert_eq!(solution, x % lcm);
+            for (residue, modulus) in congruences {
+                prop_assert_eq!((solution - residue) % modulus, 0);
+            }
+        }
+
+        #[test]
+        fn prop_factorize(n in 1..1u64 << 62) {
+            let factors = factorize(n);
+            prop_assert!(factors.iter().all(|(p, _)| is_prime(*p)));
+            prop_assert!(factors.windows(2).all(|w| w[0].0 < w[1].0));
+            prop_assert_eq!(factors.iter().map(|(p, k)| p.pow(*k)).product::<u64>(), n);
+        }
+    }
+}
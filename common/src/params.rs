@@ -0,0 +1,21 @@
+use std::collections::BTreeMap;
+
+/// Per-day puzzle parameters from the config, like the preamble length of day 9.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params {
+    values: BTreeMap<String, usize>,
+}
+
+impl Params {
+    pub fn new() -> Params {
+        Params::default()
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, value: usize) {
+        self.values.insert(key.into(), value);
+    }
+
+    pub fn get(&self, key: &str) -> Option<usize> {
+        self.values.get(key).copied()
+    }
+}
@@ -0,0 +1,196 @@
+//! Drawing simulations as text, and playing the pictures back in a terminal.
+
+use std::{
+    collections::HashSet,
+    io::{self, BufRead, Write},
+    ops::RangeInclusive,
+    thread,
+    time::Duration,
+};
+
+use crate::hex::Axial;
+
+/// One picture of a simulation, with a caption saying what it shows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    /// One line per row, each ending with a newline, like a printed `Grid`.
+    pub picture: String,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, picture: impl Into<String>) -> Frame {
+        Frame {
+            caption: caption.into(),
+            picture: picture.into(),
+        }
+    }
+}
+
+/// The frames of an animation, computed as they are shown.
+pub type Frames<'a> = Box<dyn Iterator<Item = Frame> + 'a>;
+
+/// The x and y ranges of a plot.
+pub type Bounds = (RangeInclusive<i64>, RangeInclusive<i64>);
+
+/// The smallest bounds holding all `points`, or `None` when there are none.
+pub fn bounds<'a>(points: impl IntoIterator<Item = &'a (i64, i64)>) -> Option<Bounds> {
+    points
+        .into_iter()
+        .fold(None, |bounds, (x, y)| match bounds {
+            None => Some((*x..=*x, *y..=*y)),
+            Some((xs, ys)) => Some((
+                *xs.start().min(x)..=*xs.end().max(x),
+                *ys.start().min(y)..=*ys.end().max(y),
+            )),
+        })
+}
+
+/// Draws the part of the plane within `bounds`, with `on` at the `points` and `off` elsewhere.
+pub fn plot(points: &HashSet<(i64, i64)>, bounds: &Bounds, on: char, off: char) -> String {
+    let mut picture = String::new();
+    for y in bounds.1.clone() {
+        for x in bounds.0.clone() {
+            picture.push(if points.contains(&(x, y)) { on } else { off });
+        }
+        picture.push('\n');
+    }
+    picture
+}
+
+/// Draws pointy-topped hexes, each row shifted half a hex from the one above, cropped to the
+/// `hexes` given.
+pub fn plot_hexes(hexes: &HashSet<Axial>, on: char, off: char) -> String {
+    // every hex is two columns wide, so a row half a hex to the right is one column over
+    let points: HashSet<(i64, i64)> = hexes.iter().map(|h| (2 * h.q + h.r, h.r)).collect();
+    let (xs, ys) = match bounds(&points) {
+        Some(bounds) => bounds,
+        None => return String::new(),
+    };
+
+    let mut picture = String::new();
+    for y in ys {
+        let mut row = String::new();
+        for x in xs.clone() {
+            row.push(if points.contains(&(x, y)) {
+                on
+            } else if (x - y).rem_euclid(2) == 0 {
+                off
+            } else {
+                ' '
+            });
+        }
+        picture.push_str(row.trim_end());
+        picture.push('\n');
+    }
+    picture
+}
+
+/// How fast `play` moves through the frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pace {
+    /// Waits this long after each frame.
+    Delay(Duration),
+    /// Waits for Enter after each frame, stopping on `q`.
+    Step,
+}
+
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Shows the frames one at a time on a terminal, returning how many were shown.
+pub fn play(
+    frames: Frames,
+    pace: Pace,
+    out: &mut impl Write,
+    keys: &mut impl BufRead,
+) -> io::Result<usize> {
+    let mut shown = 0;
+    for frame in frames {
+        write!(
+            out,
+            "{}{}\n\n{}",
+            CLEAR_SCREEN, frame.caption, frame.picture
+        )?;
+        shown += 1;
+
+        match pace {
+            Pace::Delay(delay) => {
+                out.flush()?;
+                thread::sleep(delay);
+            }
+            Pace::Step => {
+                write!(out, "\n[Enter] next frame, [q] quit ")?;
+                out.flush()?;
+                let mut key = String::new();
+                if keys.read_line(&mut key)? == 0 || key.trim() == "q" {
+                    break;
+                }
+            }
+        }
+    }
+    Ok(shown)
+}
+
+/// Writes the frames one after another as plain text, for reading or diffing later.
+pub fn dump(frames: Frames, out: &mut impl Write) -> io::Result<usize> {
+    let mut written = 0;
+    for frame in frames {
+        if written > 0 {
+            writeln!(out)?;
+        }
+        write!(out, "== {} ==\n{}", frame.caption, frame.picture)?;
+        written += 1;
+    }
+    out.flush()?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frames() -> Frames<'static> {
+        Box::new((1..=3).map(|i| Frame::new(format!("frame {}", i), "#".repeat(i) + "\n")))
+    }
+
+    #[test]
+    fn test_plot() {
+        let points: HashSet<(i64, i64)> = [(-1, 0), (1, 1)].into();
+        let bounds = bounds(&points).unwrap();
+        assert_eq!(bounds, (-1..=1, 0..=1));
+        assert_eq!(plot(&points, &bounds, '#', '.'), "#..\n..#\n");
+        assert_eq!(plot(&points, &(0..=1, 1..=1), '#', '.'), ".#\n");
+        assert_eq!(super::bounds(&HashSet::new()), None);
+    }
+
+    #[test]
+    fn test_plot_hexes() {
+        let hexes: HashSet<Axial> = Axial::default().neighbours().collect();
+        assert_eq!(plot_hexes(&hexes, '#', '.'), " # #\n# . #\n # #\n");
+        assert_eq!(plot_hexes(&HashSet::new(), '#', '.'), "");
+    }
+
+    #[test]
+    fn test_play() {
+        let mut out = vec![];
+        let shown = play(frames(), Pace::Step, &mut out, &mut "\nq\n".as_bytes()).unwrap();
+        assert_eq!(shown, 2);
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches(CLEAR_SCREEN).count(), 2);
+        assert!(out.contains("frame 2\n\n##\n"));
+
+        let mut out = vec![];
+        let pace = Pace::Delay(Duration::ZERO);
+        assert_eq!(play(frames(), pace, &mut out, &mut io::empty()).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_dump() {
+        let mut out = vec![];
+        assert_eq!(dump(frames(), &mut out).unwrap(), 3);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "== frame 1 ==\n#\n\n== frame 2 ==\n##\n\n== frame 3 ==\n###\n"
+        );
+    }
+}
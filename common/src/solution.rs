@@ -0,0 +1,257 @@
+use crate::{error::ParseError, explore::Explorer, params::Params, render::Frames};
+
+use std::{
+    any::{self, Any},
+    fmt,
+};
+
+use tracing::info_span;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// Wide enough to hold every signed and unsigned answer we produce.
+    Number(i128),
+    Text(String),
+    /// For parts without a puzzle, like the second half of day 25.
+    Empty,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Empty => Ok(()),
+        }
+    }
+}
+
+macro_rules! from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_number!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+// Lets tests compare against plain literals, e.g. `assert_eq!(Day7::part1(&input), 4)`.
+impl PartialEq<i64> for Answer {
+    fn eq(&self, other: &i64) -> bool {
+        matches!(self, Answer::Number(n) if *n == *other as i128)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, Answer::Text(s) if s == other)
+    }
+}
+
+/// A day's puzzle, split into parsing the input once and solving both parts from the result.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Like `parse`, for days that take parameters besides their input.
+    fn parse_with(input: &str, _params: &Params) -> Result<Self::Input, ParseError> {
+        Self::parse(input)
+    }
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Frames showing how the simulation behind `part` evolves, for days that have one.
+    fn animate(_input: &Self::Input, _part: u32) -> Option<Frames<'_>> {
+        None
+    }
+
+    /// Commands for exploring the input in `aoc repl`, for days that have some.
+    fn explore(_input: &Self::Input) -> Option<Explorer<'_>> {
+        None
+    }
+}
+
+/// Parses `input` and solves both parts, for calling a day from other code.
+pub fn solve<S: Solution>(input: &str) -> Result<(Answer, Answer), ParseError> {
+    let input = S::parse(input)?;
+    Ok((S::part1(&input), S::part2(&input)))
+}
+
+/// Parsed input of some day, see `Solver::parse`.
+pub type Parsed = Box<dyn Any>;
+
+/// A `Solution` with its input type erased, so that every day can be stored and run the same way.
+#[derive(Clone, Copy)]
+pub struct Solver {
+    parse: fn(&str, &Params) -> Result<Parsed, ParseError>,
+    part1: fn(&Parsed) -> Answer,
+    part2: fn(&Parsed) -> Answer,
+    animate: fn(&Parsed, u32) -> Option<Frames<'_>>,
+    explore: fn(&Parsed) -> Option<Explorer<'_>>,
+}
+
+/// The crate a solution lives in, like `day7`, to tell the days apart in the logs.
+fn day<S>() -> &'static str {
+    let name = any::type_name::<S>();
+    name.split("::").next().unwrap_or(name)
+}
+
+fn parse<S: Solution>(input: &str, params: &Params) -> Result<Parsed, ParseError>
+where
+    S::Input: 'static,
+{
+    let _span = info_span!("parse", day = day::<S>()).entered();
+    S::parse_with(input, params).map(|parsed| Box::new(parsed) as Parsed)
+}
+
+fn downcast<S: Solution>(parsed: &Parsed) -> &S::Input
+where
+    S::Input: 'static,
+{
+    parsed
+        .downcast_ref::<S::Input>()
+        .expect("parsed input belongs to another solution")
+}
+
+fn part1<S: Solution>(parsed: &Parsed) -> Answer
+where
+    S::Input: 'static,
+{
+    let _span = info_span!("part1", day = day::<S>()).entered();
+    S::part1(downcast::<S>(parsed))
+}
+
+fn part2<S: Solution>(parsed: &Parsed) -> Answer
+where
+    S::Input: 'static,
+{
+    let _span = info_span!("part2", day = day::<S>()).entered();
+    S::part2(downcast::<S>(parsed))
+}
+
+fn animate<S: Solution>(parsed: &Parsed, part: u32) -> Option<Frames<'_>>
+where
+    S::Input: 'static,
+{
+    S::animate(downcast::<S>(parsed), part)
+}
+
+fn explore<S: Solution>(parsed: &Parsed) -> Option<Explorer<'_>>
+where
+    S::Input: 'static,
+{
+    S::explore(downcast::<S>(parsed))
+}
+
+impl Solver {
+    pub const fn new<S: Solution>() -> Solver
+    where
+        S::Input: 'static,
+    {
+        Solver {
+            parse: parse::<S>,
+            part1: part1::<S>,
+            part2: part2::<S>,
+            animate: animate::<S>,
+            explore: explore::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Parsed, ParseError> {
+        (self.parse)(input, &Params::new())
+    }
+
+    pub fn parse_with(&self, input: &str, params: &Params) -> Result<Parsed, ParseError> {
+        (self.parse)(input, params)
+    }
+
+    /// Solves part 1 or 2 from input returned by `parse` on this solver.
+    pub fn part(&self, parsed: &Parsed, part: u32) -> Answer {
+        match part {
+            1 => (self.part1)(parsed),
+            2 => (self.part2)(parsed),
+            _ => panic!("There is no part {}", part),
+        }
+    }
+
+    /// The animation of part 1 or 2, from input returned by `parse` on this solver.
+    pub fn animate<'a>(&self, parsed: &'a Parsed, part: u32) -> Option<Frames<'a>> {
+        (self.animate)(parsed, part)
+    }
+
+    /// The day's REPL commands, on input returned by `parse` on this solver.
+    pub fn explore<'a>(&self, parsed: &'a Parsed) -> Option<Explorer<'a>> {
+        (self.explore)(parsed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+            crate::ints(input)
+        }
+
+        fn part1(input: &Vec<i64>) -> Answer {
+            input.iter().sum::<i64>().into()
+        }
+
+        fn part2(input: &Vec<i64>) -> Answer {
+            format!("{} numbers", input.len()).into()
+        }
+    }
+
+    #[test]
+    fn test_solver() {
+        let solver = Solver::new::<Sum>();
+        let parsed = solver.parse("1\n2\n-4").unwrap();
+        assert_eq!(solver.part(&parsed, 1), -1);
+        assert_eq!(solver.part(&parsed, 2), "3 numbers");
+        assert_eq!(solver.parse("1\n+").unwrap_err().line, 2);
+        assert!(solver.animate(&parsed, 1).is_none());
+    }
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            solve::<Sum>("1\n2"),
+            Ok((Answer::from(3), Answer::from("2 numbers")))
+        );
+        assert!(solve::<Sum>("1\ntwo").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            Answer::from(20899048083289u64).to_string(),
+            "20899048083289"
+        );
+        assert_eq!(Answer::from("mxmxvkd,sqjhc").to_string(), "mxmxvkd,sqjhc");
+        assert_eq!(Answer::Empty.to_string(), "");
+    }
+}
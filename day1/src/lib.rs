@@ -0,0 +1,54 @@
+use aoc_common::{ints, Answer, ParseError, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        ints(input)
+    }
+
+    fn part1(expenses: &Vec<i64>) -> Answer {
+        let mut result = 0;
+
+        for l in expenses {
+            for r in expenses {
+                if l != r && l + r == 2020 {
+                    result = l * r
+                }
+            }
+        }
+
+        result.into()
+    }
+
+    fn part2(expenses: &Vec<i64>) -> Answer {
+        let mut result = 0;
+
+        for l in expenses {
+            for r in expenses {
+                for m in expenses {
+                    if l != r && r != m && l + r + m == 2020 {
+                        result = l * r * m;
+                    }
+                }
+            }
+        }
+
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day1::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day1::part1(&input), 514579);
+        assert_eq!(Day1::part2(&input), 241861950);
+    }
+}
@@ -0,0 +1,93 @@
+use aoc_common::{ints, Answer, ParseError, Solution};
+use tracing::trace;
+
+fn chain(input: &str) -> Result<Vec<i64>, ParseError> {
+    let mut nums = ints(input)?;
+    nums.push(0);
+    nums.sort();
+    Ok(nums)
+}
+
+fn joltage_differences(nums: &[i64]) -> i64 {
+    trace!("{:?}", nums);
+
+    let mut one_diffs = 0;
+    let mut three_diffs = 1;
+
+    for i in 0..nums.len() - 1 {
+        trace!("{} - {} = {}", nums[i + 1], nums[i], nums[i + 1] - nums[i]);
+        match nums[i + 1] - nums[i] {
+            1 => one_diffs += 1,
+            3 => three_diffs += 1,
+            n => panic!("Unexpected diff: {}", n),
+        }
+    }
+
+    one_diffs * three_diffs
+}
+
+fn multiplier(n: usize) -> u64 {
+    match n {
+        3 => 7, // 3! + 1
+        2 => 4, // all, a, b, none
+        1 => 2, // all, none
+        _ => panic!("Unsupported n: {}", n),
+    }
+}
+
+fn arrangements(nums: &[i64]) -> u64 {
+    let mut nums = nums.to_vec();
+    nums.push(nums.last().unwrap() + 3);
+
+    let mut ones = 0;
+    let mut product = 1;
+
+    for i in 0..nums.len() - 1 {
+        match nums[i + 1] - nums[i] {
+            3 => {
+                if ones > 1 {
+                    product *= multiplier(ones - 1);
+                }
+                ones = 0;
+            }
+            1 => ones += 1,
+            n => panic!("Unexpected diff: {}", n),
+        }
+    }
+
+    product
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        chain(input)
+    }
+
+    fn part1(nums: &Vec<i64>) -> Answer {
+        joltage_differences(nums).into()
+    }
+
+    fn part2(nums: &Vec<i64>) -> Answer {
+        arrangements(nums).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day10::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day10::part1(&input), 7 * 5);
+        assert_eq!(Day10::part2(&input), 8);
+        let input = Day10::parse(&string_from_file("example2.txt")).unwrap();
+        assert_eq!(Day10::part1(&input), 22 * 10);
+        assert_eq!(Day10::part2(&input), 19208);
+    }
+}
@@ -0,0 +1,162 @@
+use aoc_common::{
+    Answer, Automaton, Frame, Frames, Grid, Life, ParseError, Solution, Space, NEIGHBOURS8,
+};
+
+use std::{collections::HashMap, iter};
+
+fn build_grid(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(
+        input,
+        input,
+        |c| if "L#.".contains(c) { Some(c) } else { None },
+        "'L', '#' or '.'",
+    )
+}
+
+/// The seats of the waiting area, each with the seats that people sitting there care about.
+struct Seats {
+    neighbours: HashMap<(usize, usize), Vec<(usize, usize)>>,
+}
+
+impl Seats {
+    fn new(grid: &Grid<char>, neighbours: impl Fn((usize, usize)) -> Vec<(usize, usize)>) -> Seats {
+        Seats {
+            neighbours: grid
+                .positions()
+                .filter(|pos| grid[*pos] != '.')
+                .map(|pos| (pos, neighbours(pos)))
+                .collect(),
+        }
+    }
+
+    /// The seats right next to each seat.
+    fn adjacent(grid: &Grid<char>) -> Seats {
+        Seats::new(grid, |pos| {
+            grid.neighbours8(pos).filter(|n| grid[*n] != '.').collect()
+        })
+    }
+
+    /// The first seat that can be seen in each of the eight directions from each seat.
+    fn visible(grid: &Grid<char>) -> Seats {
+        Seats::new(grid, |pos| {
+            NEIGHBOURS8
+                .iter()
+                .filter_map(|dir| grid.ray(pos, *dir).find(|p| grid[*p] != '.'))
+                .collect()
+        })
+    }
+}
+
+impl Space for Seats {
+    type Cell = (usize, usize);
+
+    fn neighbours(&self, pos: &(usize, usize)) -> Vec<(usize, usize)> {
+        self.neighbours[pos].clone()
+    }
+
+    // empty seats with no one around get taken, so every seat has to be looked at
+    fn cells(&self) -> Option<Vec<(usize, usize)>> {
+        Some(self.neighbours.keys().copied().collect())
+    }
+}
+
+/*
+If a seat is empty (L) and there are no occupied seats adjacent to it, the seat becomes occupied.
+If a seat is occupied (#) and four or more seats adjacent to it are also occupied, the seat becomes empty.
+Otherwise, the seat's state does not change.
+Floor (.) never changes; seats don't move, and nobody sits on the floor.
+*/
+const ADJACENT_RULES: Life = Life {
+    birth: &[0],
+    survival: &[0, 1, 2, 3],
+};
+
+// people are more tolerant when looking at the first seats they can see
+const VISIBLE_RULES: Life = Life {
+    birth: &[0],
+    survival: &[0, 1, 2, 3, 4],
+};
+
+fn waiting_area(grid: &Grid<char>, seats: Seats) -> Automaton<Seats> {
+    let occupied = grid.positions().filter(|pos| grid[*pos] == '#');
+    Automaton::new(seats, occupied)
+}
+
+fn occupied_when_settled(grid: &Grid<char>, seats: Seats, rules: &Life) -> u64 {
+    let mut area = waiting_area(grid, seats);
+    area.settle(rules);
+
+    area.count() as u64
+}
+
+/// Every round of people sitting down and getting up, until no one moves any more.
+fn rounds<'a>(grid: &'a Grid<char>, seats: Seats, rules: &'a Life) -> Frames<'a> {
+    let mut area = waiting_area(grid, seats);
+    let mut round = 0;
+    let mut changed = true;
+
+    Box::new(iter::from_fn(move || {
+        if round > 0 {
+            changed = changed && area.step(rules);
+        }
+        if !changed {
+            return None;
+        }
+
+        let picture = grid.map(|pos, c| match c {
+            '.' => '.',
+            _ if area.is_alive(&pos) => '#',
+            _ => 'L',
+        });
+        let caption = format!("Round {}: {} seats occupied", round, area.count());
+        round += 1;
+        Some(Frame::new(caption, picture.to_string()))
+    }))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        build_grid(input)
+    }
+
+    fn part1(grid: &Grid<char>) -> Answer {
+        occupied_when_settled(grid, Seats::adjacent(grid), &ADJACENT_RULES).into()
+    }
+
+    fn part2(grid: &Grid<char>) -> Answer {
+        occupied_when_settled(grid, Seats::visible(grid), &VISIBLE_RULES).into()
+    }
+
+    fn animate(grid: &Grid<char>, part: u32) -> Option<Frames<'_>> {
+        Some(match part {
+            1 => rounds(grid, Seats::adjacent(grid), &ADJACENT_RULES),
+            _ => rounds(grid, Seats::visible(grid), &VISIBLE_RULES),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day11::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day11::part1(&input), 37);
+        assert_eq!(Day11::part2(&input), 26);
+    }
+
+    #[test]
+    fn test_animate() {
+        let input = Day11::parse(&string_from_file("example.txt")).unwrap();
+        let frames: Vec<Frame> = Day11::animate(&input, 1).unwrap().collect();
+        assert_eq!(frames.len(), 6);
+        assert!(frames[1].picture.starts_with("#.##.##.##\n"));
+        assert_eq!(frames[5].caption, "Round 5: 37 seats occupied");
+    }
+}
@@ -0,0 +1,182 @@
+use aoc_common::{number, Answer, ParseError, Solution};
+use tracing::trace;
+
+const DIRECTIONS: [char; 4] = ['E', 'S', 'W', 'N'];
+
+#[derive(Debug)]
+pub struct Move {
+    direction: char,
+    amount: i64,
+}
+
+#[derive(Debug)]
+struct Ship {
+    facing_direction: usize,
+    x: i64,
+    y: i64,
+}
+
+impl Ship {
+    fn rotate(&mut self, rotation: char, amount: i64) {
+        let mut facing = self.facing_direction as i64;
+        match rotation {
+            'L' => facing -= amount / 90,
+            'R' => facing += amount / 90,
+            _ => panic!("Unkown rotation: {}", rotation),
+        }
+        self.facing_direction = ((facing + 4) % 4) as usize;
+    }
+
+    fn direction(&self) -> char {
+        DIRECTIONS[self.facing_direction]
+    }
+}
+
+#[derive(Debug)]
+struct Waypoint {
+    x: i64,
+    y: i64,
+}
+
+impl Waypoint {
+    fn rotate(&mut self, rotation: char, amount: i64) {
+        for _ in 0..amount / 90 {
+            let (x, y) = match rotation {
+                // counter clock
+                'L' => (self.y, -self.x),
+                // clock
+                'R' => (-self.y, self.x),
+                _ => panic!("Unkown rotation: {}", rotation),
+            };
+            self.x = x;
+            self.y = y;
+        }
+    }
+}
+
+fn parse_moves(input: &str) -> Result<Vec<Move>, ParseError> {
+    input
+        .lines()
+        .map(|l| {
+            let direction = l
+                .chars()
+                .next()
+                .filter(|c| "NSEWLRF".contains(*c))
+                .ok_or_else(|| ParseError::new(input, l, "an action like 'F10'"))?;
+            let amount = number(input, &l[1..])?;
+            if (direction == 'L' || direction == 'R') && amount % 90 != 0 {
+                return Err(ParseError::new(
+                    input,
+                    &l[1..],
+                    "a turn in multiples of 90 degrees",
+                ));
+            }
+            Ok(Move { direction, amount })
+        })
+        .collect()
+}
+
+fn to_offset(direction: char, amount: i64) -> (i64, i64) {
+    match direction {
+        'N' => (0, -amount),
+        'S' => (0, amount),
+        'W' => (-amount, 0),
+        'E' => (amount, 0),
+        _ => panic!("Unsupported direction: {}", direction),
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Move>;
+
+    fn parse(input: &str) -> Result<Vec<Move>, ParseError> {
+        parse_moves(input)
+    }
+
+    fn part1(moves: &Vec<Move>) -> Answer {
+        let mut ship = Ship {
+            facing_direction: 0,
+            x: 0,
+            y: 0,
+        };
+
+        for m in moves {
+            let should_move = match m.direction {
+                // moves that rotate
+                'L' | 'R' => {
+                    ship.rotate(m.direction, m.amount);
+                    None
+                }
+                // rest
+                'N' | 'S' | 'E' | 'W' => Some(m.direction),
+                // forward
+                'F' => Some(ship.direction()),
+                m => panic!("Unknown move: {}", m),
+            };
+
+            if let Some(direction) = should_move {
+                let offset = to_offset(direction, m.amount);
+                ship.x += offset.0;
+                ship.y += offset.1;
+            }
+            trace!("ship: {:?}, move: {:?}", ship, m);
+        }
+
+        (ship.x.abs() + ship.y.abs()).into()
+    }
+
+    fn part2(moves: &Vec<Move>) -> Answer {
+        let mut ship = Ship {
+            facing_direction: 0,
+            x: 0,
+            y: 0,
+        };
+        let mut waypoint = Waypoint { x: 10, y: -1 };
+
+        for m in moves {
+            match m.direction {
+                // moves that rotate
+                'L' | 'R' => waypoint.rotate(m.direction, m.amount),
+                // rest
+                'N' | 'S' | 'E' | 'W' => {
+                    let offset = to_offset(m.direction, m.amount);
+                    waypoint.x += offset.0;
+                    waypoint.y += offset.1;
+                }
+                // forward
+                'F' => {
+                    ship.x += waypoint.x * m.amount;
+                    ship.y += waypoint.y * m.amount;
+                }
+                m => panic!("Unknown move: {}", m),
+            };
+            trace!("ship: {:?}, waypoint: {:?}, move: {:?}", ship, waypoint, m);
+        }
+
+        (ship.x.abs() + ship.y.abs()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day12::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day12::part1(&input), 25);
+        assert_eq!(Day12::part2(&input), 286);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day12::parse("F10\nN3\nX7").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (3, 1, "X7"));
+
+        let err = Day12::parse("F10\nR45").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 2, "45"));
+    }
+}
@@ -0,0 +1,153 @@
+use aoc_common::{math::crt, number, Answer, ParseError, Solution};
+use tracing::debug;
+
+#[derive(Debug)]
+pub struct Notes {
+    timestamp: i64,
+    /// Bus ids in schedule order, `None` for an `x`.
+    buses: Vec<Option<i64>>,
+}
+
+fn parse_notes(input: &str) -> Result<Notes, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+    if lines.len() < 2 {
+        return Err(ParseError::new(
+            input,
+            &input[input.len()..],
+            "a timestamp and a line of buses",
+        ));
+    }
+    Ok(Notes {
+        timestamp: number(input, lines[0])?,
+        buses: lines[1]
+            .split(',')
+            .map(|b| match b {
+                "x" => Ok(None),
+                b => number(input, b).map(Some),
+            })
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+fn earliest_bus(notes: &Notes) -> i64 {
+    let ts = notes.timestamp;
+    let buses: Vec<i64> = notes.buses.iter().flatten().copied().collect();
+
+    debug!("timestamp: {}, buses: {:?}", ts, buses);
+
+    // version with iterators. unreadable, or?
+    // am i so out of touch? no. it's the children who are wrong.
+    /*buses
+    .iter()
+    .map(|bus| [(bus - timestamp % bus) % bus, *bus])
+    .min_by_key(|t| t[0])
+    .unwrap()
+    .iter()
+    .product::<i64>()*/
+
+    let mut min_waiting: i64 = i64::MAX;
+    let mut chosen_bus = -1;
+    for bus in buses {
+        let waiting_time = (bus - ts % bus) % bus;
+        if waiting_time < min_waiting {
+            min_waiting = waiting_time;
+            chosen_bus = bus;
+        }
+    }
+
+    min_waiting * chosen_bus
+}
+
+fn earliest_departures(notes: &Notes) -> i64 {
+    // bus b leaving i minutes after t means t ≡ -i (mod b)
+    let buses: Vec<(i64, i64)> = notes
+        .buses
+        .iter()
+        .enumerate()
+        .filter_map(|(i, bus)| bus.map(|b| (-(i as i64), b)))
+        .collect();
+
+    crt(&buses).unwrap().0
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Notes;
+
+    fn parse(input: &str) -> Result<Notes, ParseError> {
+        parse_notes(input)
+    }
+
+    fn part1(notes: &Notes) -> Answer {
+        earliest_bus(notes).into()
+    }
+
+    fn part2(notes: &Notes) -> Answer {
+        earliest_departures(notes).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{math::primes, string_from_file};
+    use proptest::prelude::*;
+
+    /// Notes with up to 8 distinct prime buses below 100, each after up to 3 `x`s.
+    fn notes() -> impl Strategy<Value = Notes> {
+        let buses: Vec<i64> = primes(100).into_iter().map(|p| p as i64).collect();
+        (
+            prop::sample::subsequence(buses, 1..=8).prop_shuffle(),
+            prop::collection::vec(0..=3usize, 8),
+            1..1_000_000i64,
+        )
+            .prop_map(|(buses, gaps, timestamp)| {
+                let mut schedule = vec![];
+                for (bus, gap) in buses.into_iter().zip(gaps) {
+                    schedule.extend(vec![None; gap]);
+                    schedule.push(Some(bus));
+                }
+                Notes {
+                    timestamp,
+                    buses: schedule,
+                }
+            })
+    }
+
+    #[test]
+    fn test() {
+        let input = Day13::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day13::part1(&input), 295);
+        assert_eq!(Day13::part2(&input), 1068781);
+
+        // bus 59 leaves right at 944, so there is no wait
+        let input = Day13::parse("944\n7,13,x,x,59,x,31,19").unwrap();
+        assert_eq!(Day13::part1(&input), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_earliest_bus(notes in notes()) {
+            let (wait, bus) = (notes.timestamp..)
+                .find_map(|t| {
+                    let bus = notes.buses.iter().flatten().find(|bus| t % *bus == 0)?;
+                    Some((t - notes.timestamp, *bus))
+                })
+                .unwrap();
+            prop_assert_eq!(earliest_bus(&notes), wait * bus);
+        }
+
+        #[test]
+        fn prop_earliest_departures(notes in notes()) {
+            let t = earliest_departures(&notes);
+            let product: i64 = notes.buses.iter().flatten().product();
+            prop_assert!((0..product).contains(&t));
+            for (i, bus) in notes.buses.iter().enumerate() {
+                if let Some(bus) = bus {
+                    prop_assert_eq!((t + i as i64) % bus, 0, "bus {} at {}", bus, i);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use aoc_common::{number, Answer, ParseError, Solution};
+
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub enum Instruction {
+    Mask(String),
+    Write { address: u64, value: u64 },
+}
+
+fn parse_program(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (target, value) = line
+                .split_once(" = ")
+                .ok_or_else(|| ParseError::new(input, line, "'mask = ...' or 'mem[...] = ...'"))?;
+            if target == "mask" {
+                if let Some((i, chr)) = value.char_indices().find(|(_, c)| !"01X".contains(*c)) {
+                    return Err(ParseError::new(
+                        input,
+                        &value[i..i + chr.len_utf8()],
+                        "'0', '1' or 'X'",
+                    ));
+                }
+                if value.len() != 36 {
+                    return Err(ParseError::new(input, value, "a mask of 36 bits"));
+                }
+                return Ok(Instruction::Mask(value.to_string()));
+            }
+            let address = target
+                .strip_prefix("mem[")
+                .and_then(|t| t.strip_suffix(']'))
+                .ok_or_else(|| ParseError::new(input, target, "'mask' or 'mem[...]'"))?;
+            Ok(Instruction::Write {
+                address: number(input, address)?,
+                value: number(input, value)?,
+            })
+        })
+        .collect()
+}
+
+fn run_value_masks(program: &[Instruction]) -> u64 {
+    let mut and_mask = 0;
+    let mut or_mask = 0;
+    let mut memory: HashMap<u64, u64> = HashMap::new();
+
+    for instruction in program {
+        match instruction {
+            Instruction::Mask(mask) => {
+                and_mask = u64::from_str_radix(&mask.replace("X", "1"), 2).unwrap();
+                or_mask = u64::from_str_radix(&mask.replace("X", "0"), 2).unwrap();
+            }
+            Instruction::Write { address, value } => {
+                let mut value = *value;
+                value &= and_mask;
+                value |= or_mask;
+                memory.insert(*address, value);
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+fn generate_addresses(addr: &str, mask: &str) -> Vec<u64> {
+    let mut addresses = vec![];
+    let mut chars: Vec<char> = addr.chars().collect();
+    let maskchars: Vec<char> = mask.chars().collect();
+
+    let mut x_positions = vec![];
+    for i in 0..chars.len() {
+        match maskchars[i] {
+            'X' => {
+                chars[i] = 'X';
+                x_positions.push(i);
+            }
+            '1' => chars[i] = '1',
+            _ => {}
+        }
+    }
+    for i in 0..1u64 << x_positions.len() {
+        let this_mask = &format!("{:0>36b}", i).chars().collect::<Vec<char>>();
+        for j in 0..x_positions.len() {
+            chars[x_positions[j]] = this_mask[this_mask.len() - 1 - j];
+        }
+        addresses.push(u64::from_str_radix(&chars.iter().collect::<String>(), 2).unwrap());
+    }
+
+    addresses
+}
+
+fn run_address_masks(program: &[Instruction]) -> u64 {
+    let mut memory: HashMap<u64, u64> = HashMap::new();
+    let mut mask = "";
+
+    for instruction in program {
+        match instruction {
+            Instruction::Mask(m) => {
+                mask = m;
+            }
+            Instruction::Write { address, value } => {
+                let address = &format!("{:0>36b}", address);
+
+                for addr in generate_addresses(address, mask) {
+                    memory.insert(addr, *value);
+                }
+            }
+        }
+    }
+
+    memory.values().sum()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        parse_program(input)
+    }
+
+    fn part1(program: &Vec<Instruction>) -> Answer {
+        run_value_masks(program).into()
+    }
+
+    fn part2(program: &Vec<Instruction>) -> Answer {
+        run_address_masks(program).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day14::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day14::part1(&input), 165);
+        let input = Day14::parse(&string_from_file("example2.txt")).unwrap();
+        assert_eq!(Day14::part2(&input), 208);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day14::parse(
+            "mask = X1001X
+mem[42] = 100",
+        )
+        .unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 8, "X1001X"));
+
+        let input = "mask = 000000000000000000000000000000X1001X
+mem[8] = 11
+mask = 2X";
+        let err = Day14::parse(input).unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (3, 8, "2"));
+    }
+}
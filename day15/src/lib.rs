@@ -0,0 +1,57 @@
+use aoc_common::{number, Answer, ParseError, Solution};
+
+use std::collections::HashMap;
+
+fn play(start: &[u64], max_turn: u64) -> u64 {
+    let mut said: HashMap<u64, u64> = HashMap::new();
+    let mut last = *start.last().unwrap();
+    for n in 1..start.len() + 1 {
+        said.insert(start[n - 1], n as u64);
+    }
+
+    for turn in (start.len() as u64 + 1)..max_turn + 1 {
+        let mut new = 0;
+        if let Some(when) = said.get(&last) {
+            new = turn - 1 - when;
+        }
+        said.insert(last, turn - 1);
+        last = new;
+    }
+
+    last
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        input.trim().split(',').map(|n| number(input, n)).collect()
+    }
+
+    fn part1(start: &Vec<u64>) -> Answer {
+        play(start, 2020).into()
+    }
+
+    fn part2(start: &Vec<u64>) -> Answer {
+        play(start, 30000000).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(Day15::part1(&Day15::parse("0,3,6").unwrap()), 436);
+        assert_eq!(play(&[0, 3, 6], 2020), 436);
+        assert_eq!(play(&[1, 3, 2], 2020), 1);
+        assert_eq!(play(&[2, 1, 3], 2020), 10);
+        assert_eq!(play(&[1, 2, 3], 2020), 27);
+        assert_eq!(play(&[2, 3, 1], 2020), 78);
+        assert_eq!(play(&[3, 2, 1], 2020), 438);
+        assert_eq!(play(&[3, 1, 2], 2020), 1836);
+    }
+}
@@ -0,0 +1,195 @@
+use aoc_common::{assign, number, Answer, AssignError, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+struct Range {
+    lo: u64,
+    hi: u64,
+}
+
+#[derive(Debug)]
+struct Rule {
+    name: String,
+    range1: Range,
+    range2: Range,
+}
+
+fn parse_range(input: &str, range: &str) -> Result<Range, ParseError> {
+    let (lo, hi) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::new(input, range, "a range like '1-3'"))?;
+    Ok(Range {
+        lo: number(input, lo)?,
+        hi: number(input, hi)?,
+    })
+}
+
+fn parse_rule(input: &str, line: &str) -> Result<Rule, ParseError> {
+    let expected = "a rule like 'class: 1-3 or 5-7'";
+    let (name, ranges) = line
+        .split_once(": ")
+        .ok_or_else(|| ParseError::new(input, line, expected))?;
+    let (range1, range2) = ranges
+        .split_once(" or ")
+        .ok_or_else(|| ParseError::new(input, ranges, expected))?;
+    Ok(Rule {
+        name: name.to_string(),
+        range1: parse_range(input, range1)?,
+        range2: parse_range(input, range2)?,
+    })
+}
+
+fn parse_ticket(input: &str, line: &str) -> Result<Vec<u64>, ParseError> {
+    line.split(',').map(|t| number(input, t)).collect()
+}
+
+fn rule_is_valid(rule: &Rule, field: &u64) -> bool {
+    (*field >= rule.range1.lo && *field <= rule.range1.hi)
+        || (*field >= rule.range2.lo && *field <= rule.range2.hi)
+}
+
+fn invalid_sum(ticket: &Vec<u64>, rules: &Vec<Rule>) -> u64 {
+    let mut invalid_sum = 0;
+
+    for field in ticket {
+        let mut valid = false;
+        for rule in rules {
+            if rule_is_valid(rule, field) {
+                valid |= true;
+            }
+        }
+        if !valid {
+            invalid_sum += field;
+        }
+    }
+
+    invalid_sum
+}
+
+#[derive(Debug)]
+pub struct Notes {
+    rules: Vec<Rule>,
+    my_ticket: Vec<u64>,
+    tickets: Vec<Vec<u64>>,
+}
+
+fn parse_notes(input: &str) -> Result<Notes, ParseError> {
+    let sections: Vec<&str> = input.split("\n\n").collect();
+    if sections.len() != 3 {
+        return Err(ParseError::new(
+            input,
+            &input[input.len()..],
+            "rules, your ticket and nearby tickets separated by blank lines",
+        ));
+    }
+    for (section, expected) in [
+        (sections[1], "your ticket:"),
+        (sections[2], "nearby tickets:"),
+    ] {
+        let header = section.lines().next().unwrap_or(section);
+        if header != expected {
+            return Err(ParseError::new(input, header, format!("'{}'", expected)));
+        }
+    }
+    let my_ticket = sections[1]
+        .lines()
+        .nth(1)
+        .ok_or_else(|| ParseError::new(input, &sections[1][sections[1].len()..], "your ticket"))?;
+
+    Ok(Notes {
+        rules: sections[0]
+            .lines()
+            .map(|l| parse_rule(input, l))
+            .collect::<Result<_, _>>()?,
+        my_ticket: parse_ticket(input, my_ticket)?,
+        tickets: sections[2]
+            .lines()
+            .skip(1)
+            .map(|l| parse_ticket(input, l))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// Works out which field is at each position of the tickets.
+fn field_order(notes: &Notes) -> Result<Vec<&str>, AssignError<usize>> {
+    let tickets: Vec<&Vec<u64>> = notes
+        .tickets
+        .iter()
+        .filter(|t| invalid_sum(t, &notes.rules) == 0)
+        .collect();
+
+    // the fields each position could be, which are those whose rule every ticket's value satisfies
+    let candidates: HashMap<usize, HashSet<&str>> = (0..notes.my_ticket.len())
+        .map(|i| {
+            let fields = notes
+                .rules
+                .iter()
+                .filter(|rule| tickets.iter().all(|t| rule_is_valid(rule, &t[i])))
+                .map(|rule| rule.name.as_ref())
+                .collect();
+            (i, fields)
+        })
+        .collect();
+
+    let fields = assign(&candidates)?;
+    Ok((0..notes.my_ticket.len()).map(|i| fields[&i]).collect())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Notes;
+
+    fn parse(input: &str) -> Result<Notes, ParseError> {
+        parse_notes(input)
+    }
+
+    fn part1(notes: &Notes) -> Answer {
+        notes
+            .tickets
+            .iter()
+            .map(|t| invalid_sum(t, &notes.rules))
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part2(notes: &Notes) -> Answer {
+        field_order(notes)
+            .unwrap_or_else(|err| panic!("Can't tell the fields apart: {}", err))
+            .iter()
+            .zip(&notes.my_ticket)
+            .filter(|(name, _)| name.starts_with("departure"))
+            .map(|(_, value)| value)
+            .product::<u64>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day16::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day16::part1(&input), 71);
+        let input = Day16::parse(&string_from_file("example2.txt")).unwrap();
+        assert_eq!(field_order(&input).unwrap(), vec!["row", "class", "seat"]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "class: 1-3 or 5-7\nrow: 6-11 of 33-44\n\nyour ticket:\n7,1,14\n\nnearby tickets:\n7,3,47";
+        let err = Day16::parse(input).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.token.as_str()),
+            (2, 6, "6-11 of 33-44")
+        );
+
+        let input =
+            "class: 1-3 or 5-7\n\nyour ticket:\n7,1,14\n\nnearby tickets:\n7,3,47\n40,4x,50";
+        let err = Day16::parse(input).unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (8, 4, "4x"));
+    }
+}
@@ -0,0 +1,150 @@
+use aoc_common::{
+    render, Answer, Automaton, Frame, Frames, Grid, Lattice, Life, ParseError, Solution,
+};
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    iter,
+};
+
+/// Active cubes stay active with 2 or 3 active neighbours, inactive ones activate with exactly 3.
+const CONWAY_CUBES: Life = Life {
+    birth: &[3],
+    survival: &[2, 3],
+};
+
+const CYCLES: usize = 6;
+
+/// The `N`-dimensional pocket dimension, with the `grid` slice active.
+fn pocket_dimension<const N: usize>(grid: &Grid<bool>) -> Automaton<Lattice<N>> {
+    let active = grid.positions().filter(|pos| grid[*pos]).map(|(x, y)| {
+        let mut cube = [0; N];
+        cube[0] = x as i64;
+        cube[1] = y as i64;
+        cube
+    });
+
+    Automaton::new(Lattice::<N>::new(), active)
+}
+
+/// The active cubes after six cycles, starting from the `grid` slice of an `N`-dimensional space.
+fn boot<const N: usize>(grid: &Grid<bool>) -> usize {
+    let mut space = pocket_dimension::<N>(grid);
+    space.run(&CONWAY_CUBES, CYCLES);
+    space.count()
+}
+
+/// The x-y slices of the active cubes, labelled like `z=-1, w=0` by their other coordinates.
+fn slices<const N: usize>(space: &Automaton<Lattice<N>>) -> String {
+    let mut slices: BTreeMap<Vec<i64>, HashSet<(i64, i64)>> = BTreeMap::new();
+    for cube in space.alive() {
+        slices
+            .entry(cube[2..].to_vec())
+            .or_default()
+            .insert((cube[0], cube[1]));
+    }
+    let all: Vec<(i64, i64)> = slices.values().flatten().copied().collect();
+    let bounds = match render::bounds(&all) {
+        Some(bounds) => bounds,
+        None => return String::new(),
+    };
+
+    let pictures: Vec<String> = slices
+        .iter()
+        .map(|(coords, slice)| {
+            let label: Vec<String> = coords
+                .iter()
+                .zip(["z", "w"])
+                .map(|(c, axis)| format!("{}={}", axis, c))
+                .collect();
+            format!(
+                "{}\n{}",
+                label.join(", "),
+                render::plot(slice, &bounds, '#', '.')
+            )
+        })
+        .collect();
+    pictures.join("\n")
+}
+
+/// The boot process, one cycle at a time.
+fn cycles<const N: usize>(grid: &Grid<bool>) -> Frames<'_> {
+    let mut space = pocket_dimension::<N>(grid);
+    let mut cycle = 0;
+
+    Box::new(iter::from_fn(move || {
+        if cycle > CYCLES {
+            return None;
+        }
+        if cycle > 0 {
+            space.step(&CONWAY_CUBES);
+        }
+        let caption = format!("Cycle {}: {} active cubes", cycle, space.count());
+        cycle += 1;
+        Some(Frame::new(caption, slices(&space)))
+    }))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+        Grid::parse(
+            input,
+            input,
+            |c| match c {
+                '.' => Some(false),
+                '#' => Some(true),
+                _ => None,
+            },
+            "'.' or '#'",
+        )
+    }
+
+    fn part1(grid: &Grid<bool>) -> Answer {
+        boot::<3>(grid).into()
+    }
+
+    fn part2(grid: &Grid<bool>) -> Answer {
+        boot::<4>(grid).into()
+    }
+
+    fn animate(grid: &Grid<bool>, part: u32) -> Option<Frames<'_>> {
+        Some(match part {
+            1 => cycles::<3>(grid),
+            _ => cycles::<4>(grid),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day17::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day17::part1(&input), 112);
+        assert_eq!(Day17::part2(&input), 848);
+    }
+
+    #[test]
+    fn test_animate() {
+        let input = Day17::parse(&string_from_file("example.txt")).unwrap();
+        let frames: Vec<Frame> = Day17::animate(&input, 1).unwrap().collect();
+        assert_eq!(frames.len(), 7);
+        assert_eq!(frames[0].picture, "z=0\n.#.\n..#\n###\n");
+        // as the puzzle draws it after one cycle
+        assert_eq!(
+            frames[1].picture,
+            "z=-1\n#..\n..#\n.#.\n\nz=0\n#.#\n.##\n.#.\n\nz=1\n#..\n..#\n.#.\n"
+        );
+        assert_eq!(frames[6].caption, "Cycle 6: 112 active cubes");
+
+        let frames: Vec<Frame> = Day17::animate(&input, 2).unwrap().collect();
+        assert!(frames[1].picture.starts_with("z=-1, w=-1\n"));
+    }
+}
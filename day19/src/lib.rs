@@ -0,0 +1,221 @@
+use aoc_common::{number, Answer, Digraph, ParseError, Solution};
+
+use std::collections::HashMap;
+
+extern crate regex;
+use regex::Regex;
+
+/// How many times rule 11 may loop in part 2, which is plenty for messages of the input's length.
+const LOOP_DEPTH: usize = 8;
+
+#[derive(Debug)]
+enum Rule {
+    Char(char),
+    Alternatives(Vec<Vec<u64>>),
+}
+
+/// The rules, with an edge from each rule to the rules it refers to.
+#[derive(Debug)]
+struct Rules {
+    rules: HashMap<u64, Rule>,
+    graph: Digraph,
+}
+
+fn parse_sequence<'a>(input: &str, side: &'a str) -> Result<Vec<&'a str>, ParseError> {
+    let sequence: Vec<&str> = side.split(' ').collect();
+    for n in &sequence {
+        number::<u64>(input, n)?;
+    }
+    Ok(sequence)
+}
+
+impl Rules {
+    fn parse(input: &str, lines: &str) -> Result<Rules, ParseError> {
+        let mut rules = HashMap::new();
+        let mut graph = Digraph::new();
+        let mut defined = HashMap::new();
+        let mut references = vec![];
+
+        for line in lines.lines() {
+            let (name, body) = line
+                .split_once(": ")
+                .ok_or_else(|| ParseError::new(input, line, "a rule like '0: 4 1 5'"))?;
+            let number: u64 = number(input, name)?;
+            let from = graph.node(name);
+            defined.insert(from, line);
+
+            let rule = match body.as_bytes() {
+                [b'"', c, b'"'] => Rule::Char(*c as char),
+                _ => {
+                    let mut alternatives = vec![];
+                    for side in body.split(" | ") {
+                        let sequence = parse_sequence(input, side)?;
+                        for n in &sequence {
+                            let to = graph.node(n);
+                            graph.add_edge(from, to, ());
+                            references.push((to, *n));
+                        }
+                        alternatives.push(sequence.iter().map(|n| n.parse().unwrap()).collect());
+                    }
+                    Rule::Alternatives(alternatives)
+                }
+            };
+            rules.insert(number, rule);
+        }
+
+        if !rules.contains_key(&0) {
+            return Err(ParseError::new(input, &lines[lines.len()..], "rule 0"));
+        }
+        if let Some((_, n)) = references.iter().find(|(to, _)| !defined.contains_key(to)) {
+            return Err(ParseError::new(input, n, "a rule that is defined"));
+        }
+        if let Some(cycle) = graph.find_cycle() {
+            return Err(ParseError::new(
+                input,
+                defined[&cycle[0]],
+                "a rule that doesn't refer back to itself",
+            ));
+        }
+
+        Ok(Rules { rules, graph })
+    }
+
+    /// The regex for rule 0, built up from the rules it refers to. `special` may replace the regex
+    /// of a rule, given those of the rules below it.
+    fn regex(&self, special: impl Fn(u64, &HashMap<u64, String>) -> Option<String>) -> Regex {
+        let mut regexes: HashMap<u64, String> = HashMap::new();
+        // there are no cycles, which parsing checked
+        let order = self.graph.topological_sort().unwrap();
+
+        for id in order.into_iter().rev() {
+            let number: u64 = self.graph.name(id).parse().unwrap();
+            let regex = special(number, &regexes).unwrap_or_else(|| match &self.rules[&number] {
+                Rule::Char(c) => regex::escape(&c.to_string()),
+                Rule::Alternatives(alternatives) => {
+                    let alternatives: Vec<String> = alternatives
+                        .iter()
+                        .map(|sequence| sequence.iter().map(|n| regexes[n].as_str()).collect())
+                        .collect();
+                    format!("(?:{})", alternatives.join("|"))
+                }
+            });
+            regexes.insert(number, regex);
+        }
+
+        Regex::new(&format!("^{}$", regexes[&0])).unwrap()
+    }
+}
+
+/// Rules 8 and 11 of part 2, which refer to themselves: `8: 42 | 42 8` and `11: 42 31 | 42 11 31`.
+fn looping(rule: u64, regexes: &HashMap<u64, String>) -> Option<String> {
+    let (r42, r31) = (regexes.get(&42)?, regexes.get(&31)?);
+    match rule {
+        8 => Some(format!("(?:{})+", r42)),
+        11 => {
+            let mut regex = format!("{}{}", r42, r31);
+            for _ in 1..LOOP_DEPTH {
+                regex = format!("{}(?:{})?{}", r42, regex, r31);
+            }
+            Some(format!("(?:{})", regex))
+        }
+        _ => None,
+    }
+}
+
+#[derive(Debug)]
+pub struct Puzzle {
+    rules: Rules,
+    messages: Vec<String>,
+}
+
+fn parse_puzzle(input: &str) -> Result<Puzzle, ParseError> {
+    let (rules, messages) = input.split_once("\n\n").ok_or_else(|| {
+        ParseError::new(
+            input,
+            &input[input.len()..],
+            "rules and messages separated by a blank line",
+        )
+    })?;
+
+    let messages = messages
+        .lines()
+        .map(
+            |line| match line.char_indices().find(|(_, c)| *c != 'a' && *c != 'b') {
+                Some((i, chr)) => Err(ParseError::new(
+                    input,
+                    &line[i..i + chr.len_utf8()],
+                    "'a' or 'b'",
+                )),
+                None => Ok(line.to_string()),
+            },
+        )
+        .collect::<Result<_, _>>()?;
+
+    Ok(Puzzle {
+        rules: Rules::parse(input, rules)?,
+        messages,
+    })
+}
+
+fn count_matches(re: &Regex, messages: &[String]) -> u64 {
+    messages.iter().filter(|l| re.is_match(l)).count() as u64
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Puzzle;
+
+    fn parse(input: &str) -> Result<Puzzle, ParseError> {
+        parse_puzzle(input)
+    }
+
+    fn part1(puzzle: &Puzzle) -> Answer {
+        let re = puzzle.rules.regex(|_, _| None);
+        count_matches(&re, &puzzle.messages).into()
+    }
+
+    fn part2(puzzle: &Puzzle) -> Answer {
+        let re = puzzle.rules.regex(looping);
+        count_matches(&re, &puzzle.messages).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day19::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day19::part1(&input), 2);
+        let input = Day19::parse(&string_from_file("example2.txt")).unwrap();
+        assert_eq!(Day19::part2(&input), 12);
+    }
+
+    #[test]
+    fn test_parse_sequence() {
+        assert_eq!(parse_sequence("11 23", "11 23").unwrap(), vec!["11", "23"]);
+        assert_eq!(
+            parse_sequence("4 1 5", "4 1 5").unwrap(),
+            vec!["4", "1", "5"]
+        );
+        assert_eq!(parse_sequence("11", "11").unwrap(), vec!["11"]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day19::parse("0: 1 2\n1: \"a\"\n2: 1 x\n\nab").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (3, 6, "x"));
+
+        let err = Day19::parse("0: 1 1\n1: \"a\"\n\naa\nac").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (5, 2, "c"));
+
+        let err = Day19::parse("0: 1 2\n1: \"a\"\n\naa").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 6, "2"));
+
+        let err = Day19::parse("0: 1\n1: 2\n2: 1 1\n\naa").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
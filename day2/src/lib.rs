@@ -0,0 +1,91 @@
+use aoc_common::{captures, number, Answer, ParseError, Solution};
+
+extern crate regex;
+use regex::Regex;
+
+#[derive(Debug)]
+pub struct Policy {
+    lo: usize,
+    hi: usize,
+    chr: char,
+    password: String,
+}
+
+fn parse_policies(input: &str) -> Result<Vec<Policy>, ParseError> {
+    let re = Regex::new(r"^([0-9]+)\-([0-9]+) ([a-z]): ([a-z]+)$").unwrap();
+
+    input
+        .lines()
+        .map(|line| {
+            let cap = captures(input, line, &re, "a policy like '1-3 a: abcde'")?;
+            Ok(Policy {
+                lo: number(input, &cap[1])?,
+                hi: number(input, &cap[2])?,
+                chr: cap[3].chars().next().unwrap(),
+                password: cap[4].to_string(),
+            })
+        })
+        .collect()
+}
+
+fn num_chars_in_str(chr: char, str: &str) -> usize {
+    let mut result = 0;
+    for c in str.chars() {
+        if c == chr {
+            result += 1;
+        }
+    }
+    result
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Policy>;
+
+    fn parse(input: &str) -> Result<Vec<Policy>, ParseError> {
+        parse_policies(input)
+    }
+
+    fn part1(policies: &Vec<Policy>) -> Answer {
+        policies
+            .iter()
+            .filter(|p| {
+                let num = num_chars_in_str(p.chr, &p.password);
+                num >= p.lo && num <= p.hi
+            })
+            .count()
+            .into()
+    }
+
+    fn part2(policies: &Vec<Policy>) -> Answer {
+        policies
+            .iter()
+            .filter(|p| {
+                let chars: Vec<char> = p.password.chars().collect();
+                (chars[p.lo - 1] == p.chr) ^ (chars[p.hi - 1] == p.chr)
+            })
+            .count()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day2::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day2::part1(&input), 2);
+        assert_eq!(Day2::part2(&input), 1);
+    }
+
+    #[test]
+    fn test_num_chars_in_str() {
+        assert_eq!(num_chars_in_str('a', "abcde"), 1);
+        assert_eq!(num_chars_in_str('b', "cdefg"), 0);
+        assert_eq!(num_chars_in_str('c', "ccccccccc"), 9);
+    }
+}
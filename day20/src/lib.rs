@@ -0,0 +1,402 @@
+use aoc_common::{captures, Answer, Grid, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+use tracing::debug;
+
+use regex::Regex;
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone)]
+enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// A tile as it appears in the input, 10 rows of 10 squares.
+#[derive(Debug)]
+pub struct RawTile {
+    id: String,
+    grid: Grid<char>,
+}
+
+fn parse_tiles(input: &str) -> Result<Vec<RawTile>, ParseError> {
+    let tile_id_re: Regex = Regex::new(r"^Tile ([0-9]+):$").unwrap();
+
+    input
+        .split("\n\n")
+        .map(|block| {
+            let mut lines = block.lines();
+            let header = lines.next().unwrap_or(block);
+            let id =
+                captures(input, header, &tile_id_re, "a header like 'Tile 2311:'")?[1].to_string();
+
+            let rows = block[header.len()..].trim_start_matches('\n');
+            for row in rows.lines() {
+                if row.chars().count() != 10 {
+                    return Err(ParseError::new(input, row, "a row of 10 squares"));
+                }
+            }
+            if rows.lines().count() != 10 {
+                return Err(ParseError::new(
+                    input,
+                    &block[block.len()..],
+                    "10 rows of squares",
+                ));
+            }
+            let grid = Grid::parse(
+                input,
+                rows,
+                |c| if c == '.' || c == '#' { Some(c) } else { None },
+                "'.' or '#'",
+            )?;
+
+            Ok(RawTile { id, grid })
+        })
+        .collect()
+}
+
+/// The four edges of a tile, read left to right and top to bottom.
+fn edges(grid: &Grid<char>) -> [(Side, String); 4] {
+    [
+        (Side::Top, grid.row(0).iter().collect()),
+        (Side::Bottom, grid.row(grid.height() - 1).iter().collect()),
+        (Side::Left, grid.column(0).collect()),
+        (Side::Right, grid.column(grid.width() - 1).collect()),
+    ]
+}
+
+#[derive(Debug, Clone)]
+struct Tile {
+    id: String,
+    edges: HashSet<String>,
+    #[allow(dead_code)]
+    sides: HashMap<String, Side>,
+}
+
+impl Tile {
+    fn new(raw: &RawTile) -> Tile {
+        let mut edges = HashSet::new();
+        let mut sides = HashMap::new();
+
+        for (side, edge) in self::edges(&raw.grid).iter() {
+            let reversed = edge.chars().rev().collect::<String>();
+            edges.insert(edge.clone());
+            edges.insert(reversed.clone());
+            sides.insert(edge.clone(), side.clone());
+            sides.insert(reversed, side.clone());
+        }
+
+        Tile {
+            id: raw.id.clone(),
+            edges,
+            sides,
+        }
+    }
+}
+
+fn corner_product(raw_tiles: &[RawTile]) -> u64 {
+    let tiles = raw_tiles.iter().map(Tile::new).collect::<Vec<Tile>>();
+
+    let mut corners = vec![];
+    for t1 in &tiles {
+        let mut matches = 0;
+        for t2 in &tiles {
+            if t1.id != t2.id {
+                matches += t1
+                    .edges
+                    .intersection(&t2.edges)
+                    .collect::<Vec<&String>>()
+                    .len();
+            }
+        }
+        if matches == 4 {
+            corners.push(t1.id.clone());
+        }
+    }
+
+    corners
+        .iter()
+        .map(|id| id.parse::<u64>().unwrap())
+        .product()
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+struct Id {
+    id: String,
+    num: String,
+}
+
+impl Id {
+    fn new(id: String, num: String) -> Id {
+        Id { id, num }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Tile2 {
+    id: Id,
+    content: Grid<char>,
+    sides: HashMap<String, Side>,
+    #[allow(dead_code)]
+    side_to_content: HashMap<Side, String>,
+}
+
+impl Tile2 {
+    fn new(id: Id, content: Grid<char>) -> Tile2 {
+        let mut sides = HashMap::new();
+        let mut side_to_content = HashMap::new();
+
+        for (side, edge) in edges(&content).iter() {
+            sides.insert(edge.clone(), side.clone());
+            side_to_content.insert(side.clone(), edge.clone());
+        }
+
+        Tile2 {
+            id,
+            content,
+            sides,
+            side_to_content,
+        }
+    }
+}
+
+fn insert_tile(
+    t: &Tile2,
+    tiles: &mut HashMap<Id, Tile2>,
+    edge_to_tile_id: &mut HashMap<(Side, String), Vec<Id>>,
+) {
+    tiles.insert(t.id.clone(), t.clone());
+    for content in t.sides.keys() {
+        let side = t.sides.get(content).unwrap();
+        let key = (side.clone(), content.clone());
+        edge_to_tile_id.entry(key).or_default();
+        let list = edge_to_tile_id
+            .get_mut(&(side.clone(), content.clone()))
+            .unwrap();
+        list.push(t.id.clone());
+    }
+}
+
+const SEA_MONSTER: &str = "                  # \n#    ##    ##    ###\n #  #  #  #  #  #   ";
+
+fn find_seamonsters(sea: &Grid<char>) -> u64 {
+    let monster: Vec<(usize, usize)> = SEA_MONSTER
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.char_indices()
+                .filter(|(_, c)| *c == '#')
+                .map(move |(x, _)| (x, y))
+        })
+        .collect();
+    let width = monster.iter().map(|(x, _)| x + 1).max().unwrap();
+    let height = SEA_MONSTER.lines().count();
+    if sea.width() < width || sea.height() < height {
+        return 0;
+    }
+
+    let mut sum = 0;
+    for y in 0..=sea.height() - height {
+        for x in 0..=sea.width() - width {
+            if monster.iter().all(|(mx, my)| sea[(x + mx, y + my)] == '#') {
+                sum += 1;
+            }
+        }
+    }
+    sum
+}
+
+fn water_roughness(raw_tiles: &[RawTile]) -> u64 {
+    // for all tiles, create all 8 versions
+    // create map (string, side) -> tile
+    // pick starter (any corner)
+    // right edge -> tile -> right -> .. border
+    // bottom edge -> tile -> right -> .. border
+    // border
+
+    // build long string
+    // look for offsets for a sea monster?
+    // count amount of matches
+    // count amount of '#'
+    // answer = ^ - amount of matches * amount of '#' in sea monster (15?)
+
+    let mut edge_to_tile_id: HashMap<(Side, String), Vec<Id>> = HashMap::new();
+    let mut tiles: HashMap<Id, Tile2> = HashMap::new();
+    let names = [
+        "R0", "R0 FX", "R90", "R90 FX", "R180", "R180 FX", "R270", "R270 FX",
+    ];
+    for raw in raw_tiles {
+        for (name, content) in names.iter().zip(raw.grid.orientations()) {
+            let id = Id::new(format!("{} {}", raw.id, name), raw.id.clone());
+            insert_tile(&Tile2::new(id, content), &mut tiles, &mut edge_to_tile_id);
+        }
+    }
+
+    let mut graph: HashMap<Id, HashMap<Side, Id>> = HashMap::new();
+    let mut visited: HashSet<&str> = HashSet::new();
+    let mut stack = vec![tiles.values().next().unwrap()];
+
+    while let Some(tile) = stack.pop() {
+        visited.insert(tile.id.num.as_ref());
+        let mut node: HashMap<Side, Id> = HashMap::new();
+        for (content, side) in &tile.sides {
+            let other_side = match side {
+                Side::Top => Side::Bottom,
+                Side::Bottom => Side::Top,
+                Side::Left => Side::Right,
+                Side::Right => Side::Left,
+            };
+
+            let neighbour = edge_to_tile_id
+                .get(&(other_side.clone(), content.to_string()))
+                .unwrap()
+                .iter()
+                .filter(|id| id.num != tile.id.num)
+                .cloned()
+                .collect::<Vec<Id>>();
+
+            if !neighbour.is_empty() {
+                node.insert(side.clone(), neighbour[0].clone());
+                if !visited.contains(&neighbour[0].num.as_ref()) {
+                    stack.push(tiles.get(&neighbour[0]).unwrap());
+                }
+            }
+        }
+        graph.insert(tile.id.clone(), node);
+    }
+
+    let mut next_row = None;
+    for (key, value) in &graph {
+        if !value.contains_key(&Side::Top) && !value.contains_key(&Side::Left) {
+            next_row = Some(key);
+        }
+    }
+    let mut puzzle: Vec<Vec<Tile2>> = vec![];
+
+    while let Some(topleft) = next_row {
+        let mut row = vec![];
+        row.push(tiles.get(topleft).unwrap().clone());
+        let mut next = graph.get(topleft).unwrap().get(&Side::Right);
+        while let Some(neightbour) = next {
+            row.push(tiles.get(neightbour).unwrap().clone());
+            next = graph.get(neightbour).unwrap().get(&Side::Right);
+        }
+
+        puzzle.push(row);
+        next_row = graph.get(topleft).unwrap().get(&Side::Bottom);
+    }
+
+    // the tiles without their borders
+    let mut image = Grid::new(puzzle.len() * 8, puzzle.len() * 8, '.');
+    for (y, row) in puzzle.iter().enumerate() {
+        for (x, col) in row.iter().enumerate() {
+            image.paste((x * 8, y * 8), &col.content.sub_grid((1, 1), 8, 8));
+        }
+        let ids: Vec<String> = row.iter().map(|col| col.id.num.to_string()).collect();
+        debug!("{}", ids.join(" "));
+    }
+
+    let monsters = image
+        .orientations()
+        .iter()
+        .map(find_seamonsters)
+        .max()
+        .unwrap();
+    let monster_size = SEA_MONSTER.chars().filter(|c| *c == '#').count() as u64;
+
+    image.iter().filter(|c| **c == '#').count() as u64 - monsters * monster_size
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<RawTile>;
+
+    fn parse(input: &str) -> Result<Vec<RawTile>, ParseError> {
+        parse_tiles(input)
+    }
+
+    fn part1(tiles: &Vec<RawTile>) -> Answer {
+        corner_product(tiles).into()
+    }
+
+    fn part2(tiles: &Vec<RawTile>) -> Answer {
+        water_roughness(tiles).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test() {
+        let input = Day20::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day20::part1(&input), 20899048083289);
+        assert_eq!(Day20::part2(&input), 273);
+    }
+
+    #[test]
+    fn test_find_seamonsters_mask() {
+        let input = ".####...#####..#...###..#####..#..#.#.####..#.#..#.#...#.###...#.##.##..#.#.##.###.#.##.##.#####..##.###.####..#.####.##...#.#..##.##...#..#..###.##.#..#.#..#..##.#.#...###.##.....#...###.#...#.####.#.#....##.#..#.#.##...#..#....#..#...####..#.##...###..#.#####..#....#.##.#.#####....#.....##.##.###.....#.##..#.#...#...###..####....##..#.##...#.##.#.#.###...##.###.#..####...##..#...#.###...#.##...#.######..###.###.#######..#####...##.#..#..#.#######.####.#..##.########..#..##.#.#####..#.#...##..#....#....##..#.#########..###...#.....#..##...###.###..###....##.#...##.##.#";
+        let rows = input
+            .as_bytes()
+            .chunks(24)
+            .map(|row| row.iter().map(|b| *b as char).collect())
+            .collect();
+        assert_eq!(find_seamonsters(&Grid::from_rows(rows)), 2);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = string_from_file("example.txt").replacen("Tile 1951:", "Tile 1951", 1);
+        let err = Day20::parse(&input).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.token.as_str()),
+            (13, 1, "Tile 1951")
+        );
+
+        let input = string_from_file("example.txt").replacen("..##.#..#.", "..##.#..#", 1);
+        let err = Day20::parse(&input).unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.token.as_str()),
+            (2, 1, "..##.#..#")
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_tiles_in_any_orientation(
+            orientations in prop::collection::vec(0..8usize, 9),
+            order in Just((0..9).collect::<Vec<usize>>()).prop_shuffle(),
+        ) {
+            let tiles = Day20::parse(&string_from_file("example.txt")).unwrap();
+            let moved: Vec<RawTile> = order
+                .into_iter()
+                .zip(orientations)
+                .map(|(i, orientation)| RawTile {
+                    id: tiles[i].id.clone(),
+                    grid: tiles[i].grid.orientations().swap_remove(orientation),
+                })
+                .collect();
+            prop_assert_eq!(Day20::part1(&moved), 20899048083289);
+            prop_assert_eq!(Day20::part2(&moved), 273);
+        }
+
+        #[test]
+        fn prop_find_one_seamonster(
+            (width, height, x, y) in (20..40usize, 3..20usize)
+                .prop_flat_map(|(w, h)| (Just(w), Just(h), 0..=w - 20, 0..=h - 3))
+        ) {
+            let monster: Vec<Vec<char>> = SEA_MONSTER
+                .lines()
+                .map(|line| line.chars().map(|c| if c == '#' { '#' } else { '.' }).collect())
+                .collect();
+            let mut sea = Grid::new(width, height, '.');
+            sea.paste((x, y), &Grid::from_rows(monster));
+            prop_assert_eq!(find_seamonsters(&sea), 1);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use aoc_common::{assign, Answer, ParseError, Solution};
+
+use std::collections::{HashMap, HashSet};
+
+type Parsed = (
+    HashMap<String, Vec<HashSet<String>>>,
+    HashSet<String>,
+    Vec<HashSet<String>>,
+);
+
+fn parse_foods(input: &str) -> Result<Parsed, ParseError> {
+    let mut allergen_to_ingredients: HashMap<String, Vec<HashSet<String>>> = HashMap::new();
+    let mut all_ingredients: HashSet<String> = HashSet::new();
+    let mut ingredients_as_listed = vec![];
+
+    for line in input.lines() {
+        let (ingredients, allergens) = line
+            .split_once(" (contains ")
+            .and_then(|(ingredients, allergens)| Some((ingredients, allergens.strip_suffix(')')?)))
+            .ok_or_else(|| {
+                ParseError::new(
+                    input,
+                    line,
+                    "a food like 'mxmxvkd kfcds (contains dairy, fish)'",
+                )
+            })?;
+        let ingredients = ingredients
+            .split(" ")
+            .map(|s| s.to_string())
+            .collect::<HashSet<String>>();
+        ingredients_as_listed.push(ingredients.clone());
+
+        let allergens: Vec<&str> = allergens.split(" ").collect();
+        all_ingredients = all_ingredients
+            .union(&ingredients)
+            .map(|i| i.to_string())
+            .collect::<HashSet<String>>();
+
+        for mut allergen in allergens {
+            if allergen.ends_with(',') {
+                allergen = &allergen[0..allergen.len() - 1];
+            }
+            if !allergen_to_ingredients.contains_key(allergen) {
+                allergen_to_ingredients.insert(allergen.to_string(), vec![]);
+            }
+            let list = allergen_to_ingredients.get_mut(allergen).unwrap();
+            list.push(ingredients.clone());
+        }
+    }
+
+    Ok((
+        allergen_to_ingredients,
+        all_ingredients,
+        ingredients_as_listed,
+    ))
+}
+
+/// Works out which ingredient contains each allergen. It has to be in every food that lists the
+/// allergen, and no two allergens share an ingredient.
+fn allergens(
+    allergen_to_ingredients: &HashMap<String, Vec<HashSet<String>>>,
+) -> HashMap<String, String> {
+    let candidates: HashMap<String, HashSet<String>> = allergen_to_ingredients
+        .iter()
+        .map(|(allergen, foods)| {
+            let mut ingredients = foods[0].clone();
+            for food in &foods[1..] {
+                ingredients.retain(|i| food.contains(i));
+            }
+            (allergen.to_string(), ingredients)
+        })
+        .collect();
+
+    assign(&candidates)
+        .unwrap_or_else(|err| panic!("Can't tell which ingredient has which allergen: {}", err))
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Parsed;
+
+    fn parse(input: &str) -> Result<Parsed, ParseError> {
+        parse_foods(input)
+    }
+
+    fn part1(foods: &Parsed) -> Answer {
+        let (allergen_to_ingredients, all_ingredients, ingredients_as_listed) = foods;
+
+        let ingredients_with_allergens = allergens(allergen_to_ingredients)
+            .into_values()
+            .collect::<HashSet<String>>();
+        let ingredients_with_no_allergens = all_ingredients
+            .difference(&ingredients_with_allergens)
+            .map(|i| i.to_string())
+            .collect::<Vec<String>>();
+
+        let mut sum = 0;
+        for list in ingredients_as_listed {
+            for ingredient in &ingredients_with_no_allergens {
+                if list.contains(ingredient) {
+                    sum += 1;
+                }
+            }
+        }
+        sum.into()
+    }
+
+    fn part2(foods: &Parsed) -> Answer {
+        let (allergen_to_ingredients, _, _) = foods;
+
+        let mut allergens: Vec<(String, String)> =
+            allergens(allergen_to_ingredients).into_iter().collect();
+        allergens.sort();
+
+        allergens
+            .into_iter()
+            .map(|(_, ingredient)| ingredient)
+            .collect::<Vec<String>>()
+            .join(",")
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day21::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day21::part1(&input), 5);
+        assert_eq!(Day21::part2(&input), "mxmxvkd,sqjhc,fvjkl");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err =
+            Day21::parse("sqjhc fvjkl (contains soy)\nsqjhc mxmxvkd (contains fish").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+}
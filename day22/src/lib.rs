@@ -0,0 +1,217 @@
+use aoc_common::{number, Answer, ParseError, Solution};
+use std::collections::HashSet;
+use tracing::{debug, trace};
+
+fn parse_deck(input: &str, deck: &str) -> Result<Vec<u64>, ParseError> {
+    let mut lines = deck.lines();
+    let header = lines.next().unwrap_or(deck);
+    if header != "Player 1:" && header != "Player 2:" {
+        return Err(ParseError::new(input, header, "'Player 1:' or 'Player 2:'"));
+    }
+    lines.map(|c| number(input, c)).collect()
+}
+
+fn deck_to_str(deck: &[u64]) -> String {
+    deck.iter()
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn play_round(p1_deck: &mut Vec<u64>, p2_deck: &mut Vec<u64>) {
+    trace!("Player 1's deck: {}", deck_to_str(p1_deck));
+    trace!("Player 2's deck: {}", deck_to_str(p2_deck));
+
+    let p1_card = p1_deck.remove(0);
+    let p2_card = p2_deck.remove(0);
+
+    trace!("Player 1 plays: {}", p1_card);
+    trace!("Player 2 plays: {}", p2_card);
+
+    if p1_card > p2_card {
+        trace!("Player 1 wins the round!");
+        p1_deck.push(p1_card);
+        p1_deck.push(p2_card);
+    } else if p2_card > p1_card {
+        trace!("Player 2 wins the round!");
+        p2_deck.push(p2_card);
+        p2_deck.push(p1_card);
+    } else {
+        panic!("This can't happen");
+    }
+}
+
+fn score_deck(deck: &Vec<u64>) -> u64 {
+    let mut multiplier = deck.len() as u64;
+    let mut score = 0;
+
+    for c in deck {
+        score += multiplier * c;
+        multiplier -= 1;
+    }
+
+    score
+}
+
+fn copy_deck(deck: &[u64], amount: u64) -> Vec<u64> {
+    deck[..amount as usize].to_vec()
+}
+
+/// Plays game `num`, numbering its sub-games on from `games`, the number of games started so far.
+fn play_recursive_game(
+    num: u64,
+    games: &mut u64,
+    p1_deck: &mut Vec<u64>,
+    p2_deck: &mut Vec<u64>,
+) -> u64 {
+    let mut p1_prev_decks: HashSet<String> = HashSet::new();
+    let mut p2_prev_decks: HashSet<String> = HashSet::new();
+    let mut round = 1;
+
+    debug!("=== Game {} ===", num);
+
+    while !p1_deck.is_empty() && !p2_deck.is_empty() {
+        trace!("-- Round {} (Game {}) --", round, num);
+
+        let p1_deck_str = deck_to_str(p1_deck);
+        let p2_deck_str = deck_to_str(p2_deck);
+
+        trace!("Player 1's deck: {}", p1_deck_str);
+        trace!("Player 2's deck: {}", p2_deck_str);
+
+        if p1_prev_decks.contains(&p1_deck_str) || p2_prev_decks.contains(&p2_deck_str) {
+            trace!("Player 1 wins round {} of game {}!", round, num);
+            return 1;
+        }
+
+        p1_prev_decks.insert(p1_deck_str);
+        p2_prev_decks.insert(p2_deck_str);
+
+        /*
+        Before either player deals a card,
+        if there was a previous round in this game that had exactly the same cards
+        in the same order in the same players' decks, the game instantly ends in a win for player 1.
+        Previous rounds from other games are not considered.
+        (This prevents infinite games of Recursive Combat, which everyone agrees is a bad idea.)
+        */
+
+        let p1_card = p1_deck.remove(0);
+        let p2_card = p2_deck.remove(0);
+
+        trace!("Player 1 plays: {}", p1_card);
+        trace!("Player 2 plays: {}", p2_card);
+
+        /*
+        If both players have at least as many cards remaining in their deck as the value of the card they just drew,
+        the winner of the round is determined by playing a new game of Recursive Combat (see below).
+        */
+        let winner = if p1_card <= p1_deck.len() as u64 && p2_card <= p2_deck.len() as u64 {
+            *games += 1;
+            let game = *games;
+            let mut p1_copy = copy_deck(p1_deck, p1_card);
+            let mut p2_copy = copy_deck(p2_deck, p2_card);
+
+            trace!("Playing a sub-game to determine the winner...");
+            let winner = play_recursive_game(game, games, &mut p1_copy, &mut p2_copy);
+            trace!("... anyway, back to game {}", num);
+
+            winner
+        } else {
+            if p1_card > p2_card {
+                1
+            } else {
+                2
+            }
+        };
+
+        if winner == 1 {
+            trace!("Player 1 wins round {} of game {}!", round, num);
+            p1_deck.push(p1_card);
+            p1_deck.push(p2_card);
+        } else if winner == 2 {
+            trace!("Player 2 wins round {} of game {}!", round, num);
+            p2_deck.push(p2_card);
+            p2_deck.push(p1_card);
+        } else {
+            panic!("This can't happen");
+        }
+
+        round += 1;
+    }
+
+    if !p1_deck.is_empty() {
+        1
+    } else {
+        2
+    }
+}
+
+type Decks = (Vec<u64>, Vec<u64>);
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Decks;
+
+    fn parse(input: &str) -> Result<Decks, ParseError> {
+        let (p1, p2) = input.split_once("\n\n").ok_or_else(|| {
+            ParseError::new(
+                input,
+                &input[input.len()..],
+                "two decks separated by a blank line",
+            )
+        })?;
+        Ok((parse_deck(input, p1)?, parse_deck(input, p2)?))
+    }
+
+    fn part1(decks: &Decks) -> Answer {
+        let (mut p1_deck, mut p2_deck) = decks.clone();
+
+        let mut round = 1;
+        while !p1_deck.is_empty() && !p2_deck.is_empty() {
+            trace!("-- Round {} --", round);
+            play_round(&mut p1_deck, &mut p2_deck);
+            round += 1;
+        }
+
+        debug!("== Post-game results ==");
+        debug!("Player 1's deck: {}", deck_to_str(&p1_deck));
+        debug!("Player 2's deck: {}", deck_to_str(&p2_deck));
+
+        if !p1_deck.is_empty() {
+            score_deck(&p1_deck).into()
+        } else {
+            score_deck(&p2_deck).into()
+        }
+    }
+
+    fn part2(decks: &Decks) -> Answer {
+        let (mut p1_deck, mut p2_deck) = decks.clone();
+
+        let mut games = 1;
+        play_recursive_game(1, &mut games, &mut p1_deck, &mut p2_deck);
+
+        debug!("== Post-game results ==");
+        debug!("Player 1's deck: {}", deck_to_str(&p1_deck));
+        debug!("Player 2's deck: {}", deck_to_str(&p2_deck));
+
+        if !p1_deck.is_empty() {
+            score_deck(&p1_deck).into()
+        } else {
+            score_deck(&p2_deck).into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day22::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day22::part1(&input), 306);
+        assert_eq!(Day22::part2(&input), 291);
+    }
+}
@@ -0,0 +1,458 @@
+use aoc_common::{explore::arg, Answer, Explore, Explorer, Frame, Frames, ParseError, Solution};
+
+use std::iter;
+
+#[derive(Debug)]
+struct Cups {
+    cur: usize,
+    max: u64,
+    lookup: Vec<Cup>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Cup {
+    label: u64,
+    next: u64,
+}
+
+impl Cup {
+    fn new(label: u64) -> Cup {
+        Cup { label, next: 0 }
+    }
+}
+
+impl Cups {
+    fn new(input: &str, total: usize) -> Cups {
+        let mut lookup: Vec<Cup> = vec![Cup::new(0); total + 1];
+        let mut nums = input
+            .chars()
+            .map(|c| c.to_digit(10).unwrap() as u64)
+            .collect::<Vec<u64>>();
+        for i in 10..total + 1 {
+            nums.push(i as u64);
+        }
+        for n in &nums {
+            lookup[*n as usize] = Cup::new(*n);
+        }
+        for i in 0..nums.len() - 1 {
+            lookup[nums[i] as usize].next = lookup[nums[i + 1] as usize].label;
+        }
+        lookup[nums[total - 1] as usize].next = lookup[nums[0] as usize].label;
+        Cups {
+            cur: lookup[nums[0] as usize].label as usize,
+            max: total as u64,
+            lookup,
+        }
+    }
+
+    fn get_next(&self, label: u64) -> u64 {
+        self.lookup[label as usize].next
+    }
+
+    fn update_next(&mut self, label: u64, next: u64) {
+        self.lookup[label as usize].next = next;
+    }
+
+    /// Makes one move, returning the destination cup.
+    fn make_move(&mut self) -> u64 {
+        let cur_value = self.lookup[self.cur];
+
+        // The crab picks up the three cups that are immediately clockwise of the current cup.
+        // They are removed from the circle; cup spacing is adjusted as necessary to maintain the circle.
+        let cup1 = self.lookup[cur_value.next as usize];
+        let cup2 = self.lookup[cup1.next as usize];
+        let cup3 = self.lookup[cup2.next as usize];
+
+        // The crab selects a destination cup: the cup with a label equal to the current cup's label minus one.
+        // If this would select one of the cups that was just picked up,
+        // the crab will keep subtracting one until it finds a cup that wasn't just picked up.
+        // If at any point in this process the value goes below the lowest value on any cup's label,
+        // it wraps around to the highest value on any cup's label instead.
+        let mut destination_value = cur_value.label;
+        for _ in 0..4 {
+            destination_value -= 1;
+            if destination_value < 1 {
+                destination_value = self.max;
+            }
+            if cup1.label != destination_value
+                && cup2.label != destination_value
+                && cup3.label != destination_value
+            {
+                break;
+            }
+        }
+
+        // The crab places the cups it just picked up so that they are immediately clockwise of the destination cup.
+        // They keep the same order as when they were picked up.
+        // n1 cup1 cup2 cup3 n2 => n1.next = cup3.next
+        // d1 cup1 cup2 cup3 d2 => d1.next = cup1, cup3.next = d2
+        self.update_next(cur_value.label, cup3.next);
+        let d1 = self.lookup[destination_value as usize].label;
+        let d2 = self.get_next(destination_value);
+        self.update_next(d1, cup1.label);
+        self.update_next(cup3.label, d2);
+
+        // The crab selects a new current cup: the cup which is immediately clockwise of the current cup.
+        self.cur = self.lookup[cur_value.label as usize].next as usize;
+
+        destination_value
+    }
+
+    fn cups(&self, start: usize, len: usize) -> Vec<u64> {
+        let mut nums = vec![];
+        let mut cur = self.lookup[start];
+
+        for _ in 0..len {
+            nums.push(cur.label);
+            cur = self.lookup[cur.next as usize];
+        }
+
+        nums
+    }
+
+    fn order(&self) -> String {
+        let mut cur = self.lookup[self.lookup[1].next as usize];
+        let mut order = String::new();
+        while cur.label != 1 {
+            order.push_str(&format!("{}", cur.label));
+            cur = self.lookup[cur.next as usize];
+        }
+
+        order
+    }
+}
+
+fn labels_after_one(input: &str, moves: u64) -> String {
+    let mut cups = Cups::new(input, 9);
+    for _ in 0..moves {
+        cups.make_move();
+    }
+    cups.order()
+}
+
+/// The circle the way the puzzle shows it: clockwise, with the current cup in brackets, turned
+/// so that the current cup of move `m` is at position `m`.
+fn circle(cups: &Cups, position: usize) -> String {
+    let len = cups.max as usize;
+    let start = cups.cups(cups.cur, len)[(len - position % len) % len];
+    let circle: String = cups
+        .cups(start as usize, len)
+        .iter()
+        .map(|cup| {
+            if *cup as usize == cups.cur {
+                format!("({})", cup)
+            } else {
+                format!(" {} ", cup)
+            }
+        })
+        .collect();
+    format!("cups: {}", circle.trim_end())
+}
+
+/// The crab's moves, described like the example in the puzzle.
+fn game(labels: &str, moves: usize) -> Frames<'_> {
+    let mut cups = Cups::new(labels, 9);
+    let mut m = 0;
+
+    Box::new(iter::from_fn(move || {
+        if m > moves {
+            return None;
+        }
+        m += 1;
+        if m > moves {
+            return Some(Frame::new("Final", circle(&cups, m - 1) + "\n"));
+        }
+
+        let before = circle(&cups, m - 1);
+        let picked: Vec<String> = cups.cups(cups.cur, 4)[1..]
+            .iter()
+            .map(|cup| cup.to_string())
+            .collect();
+        let destination = cups.make_move();
+        let picture = format!(
+            "{}\npick up: {}\ndestination: {}\n",
+            before,
+            picked.join(", "),
+            destination
+        );
+        Some(Frame::new(format!("Move {}", m), picture))
+    }))
+}
+
+fn star_product(input: &str, moves: u64) -> u64 {
+    let mut cups = Cups::new(input, 1_000_000);
+    for _ in 0..moves {
+        cups.make_move();
+    }
+
+    let one = cups.lookup[1];
+    let n1 = cups.lookup[one.next as usize];
+    let n2 = cups.lookup[n1.next as usize];
+
+    n1.label * n2.label
+}
+
+/// The game with any number of cups, a few moves at a time.
+struct Crab<'a> {
+    labels: &'a str,
+    cups: Cups,
+    moves: usize,
+}
+
+impl Crab<'_> {
+    /// The circle while it fits on a line, the first cups after cup 1 once it doesn't.
+    fn show(&self) -> String {
+        if self.cups.max <= 20 {
+            circle(&self.cups, self.moves)
+        } else {
+            format!("after 1: {} ...", self.after_one(10))
+        }
+    }
+
+    fn after_one(&self, len: usize) -> String {
+        let len = len.min(self.cups.max as usize - 1);
+        let cups: Vec<String> = self.cups.cups(1, len + 1)[1..]
+            .iter()
+            .map(|cup| cup.to_string())
+            .collect();
+        cups.join(" ")
+    }
+}
+
+impl Explore for Crab<'_> {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("move [N]", "let the crab make N moves, 1 by default"),
+            ("cups [N]", "start again with N cups, 9 by default"),
+            ("after [N]", "the N cups after cup 1, 8 by default"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &str) -> Option<Result<String, String>> {
+        let result = match command {
+            "move" => arg(args, 1).map(|moves: usize| {
+                for _ in 0..moves {
+                    self.cups.make_move();
+                }
+                self.moves += moves;
+                format!("Move {}: {}", self.moves, self.show())
+            }),
+            "cups" => arg(args, 9).and_then(|total: usize| {
+                if total < 9 {
+                    return Err("There are at least 9 cups".to_string());
+                }
+                self.cups = Cups::new(self.labels, total);
+                self.moves = 0;
+                Ok(self.show())
+            }),
+            "after" => arg(args, 8).map(|len| self.after_one(len)),
+            _ => return None,
+        };
+        Some(result)
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        let labels = input.trim();
+        let mut digits: Vec<char> = labels.chars().collect();
+        digits.sort_unstable();
+        if digits != ['1', '2', '3', '4', '5', '6', '7', '8', '9'] {
+            return Err(ParseError::new(
+                input,
+                labels,
+                "the cup labels 1 to 9, each once, like '389125467'",
+            ));
+        }
+        Ok(labels.to_string())
+    }
+
+    fn part1(labels: &String) -> Answer {
+        labels_after_one(labels, 100).into()
+    }
+
+    fn part2(labels: &String) -> Answer {
+        star_product(labels, 10_000_000).into()
+    }
+
+    // a million cups don't fit on a screen, so only part 1 is animated
+    fn animate(labels: &String, part: u32) -> Option<Frames<'_>> {
+        match part {
+            1 => Some(game(labels, 100)),
+            _ => None,
+        }
+    }
+
+    fn explore(labels: &String) -> Option<Explorer<'_>> {
+        Some(Box::new(Crab {
+            labels,
+            cups: Cups::new(labels, 9),
+            moves: 0,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// The first cup labels, in any order.
+    fn labels() -> impl Strategy<Value = String> {
+        Just(vec!['1', '2', '3', '4', '5', '6', '7', '8', '9'])
+            .prop_shuffle()
+            .prop_map(|labels| labels.into_iter().collect())
+    }
+
+    /// Plays the game the slow way, with the cups in a `Vec` that starts at the current cup.
+    fn naive_moves(labels: &str, total: u64, moves: usize) -> Vec<u64> {
+        let mut cups: Vec<u64> = labels
+            .chars()
+            .map(|c| c.to_digit(10).unwrap() as u64)
+            .collect();
+        cups.extend(10..=total);
+        for _ in 0..moves {
+            let picked: Vec<u64> = cups.drain(1..4).collect();
+            let mut destination = cups[0];
+            loop {
+                destination = if destination == 1 {
+                    total
+                } else {
+                    destination - 1
+                };
+                if !picked.contains(&destination) {
+                    break;
+                }
+            }
+            let at = cups.iter().position(|cup| *cup == destination).unwrap();
+            cups.splice(at + 1..at + 1, picked);
+            cups.rotate_left(1);
+        }
+        cups
+    }
+
+    #[test]
+    fn test() {
+        assert_eq!(labels_after_one("389125467", 10), "92658374");
+        assert_eq!(labels_after_one("389125467", 100), "67384529");
+        assert_eq!(star_product("389125467", 10_000_000), 149245887792);
+        assert_eq!(
+            Day23::part1(&Day23::parse("389125467").unwrap()),
+            "67384529"
+        );
+    }
+
+    #[test]
+    fn test_make_move() {
+        /*
+        -- move 1 --
+        cups: (3) 8  9  1  2  5  4  6  7
+        pick up: 8, 9, 1
+        destination: 2
+
+        -- move 2 --
+        cups:  3 (2) 8  9  1  5  4  6  7
+        pick up: 8, 9, 1
+        destination: 7
+        */
+        let mut cups = Cups::new("389125467", 9);
+        assert_eq!(cups.cur, 3);
+        assert_eq!(cups.cups(3, 9), vec![3, 8, 9, 1, 2, 5, 4, 6, 7]);
+        cups.make_move();
+        assert_eq!(cups.cur, 2);
+        assert_eq!(cups.cups(3, 9), vec![3, 2, 8, 9, 1, 5, 4, 6, 7]);
+    }
+
+    #[test]
+    fn test_game() {
+        let frames: Vec<Frame> = game("389125467", 10).collect();
+        assert_eq!(frames.len(), 11);
+        assert_eq!(frames[0].caption, "Move 1");
+        assert_eq!(
+            frames[0].picture,
+            "cups: (3) 8  9  1  2  5  4  6  7\npick up: 8, 9, 1\ndestination: 2\n"
+        );
+        assert_eq!(
+            frames[1].picture,
+            "cups:  3 (2) 8  9  1  5  4  6  7\npick up: 8, 9, 1\ndestination: 7\n"
+        );
+        assert_eq!(
+            frames[9].picture,
+            "cups: (5) 7  4  1  8  3  9  2  6\npick up: 7, 4, 1\ndestination: 3\n"
+        );
+        assert_eq!(frames[10].caption, "Final");
+        assert_eq!(frames[10].picture, "cups:  5 (8) 3  7  4  1  9  2  6\n");
+    }
+
+    #[test]
+    fn test_explore() {
+        let labels = "389125467".to_string();
+        let mut crab = Day23::explore(&labels).unwrap();
+        let mut run = |command: &str, args: &str| crab.run(command, args).unwrap();
+        assert_eq!(
+            run("move", ""),
+            Ok("Move 1: cups:  3 (2) 8  9  1  5  4  6  7".to_string())
+        );
+        assert_eq!(
+            run("move", "9"),
+            Ok("Move 10: cups:  5 (8) 3  7  4  1  9  2  6".to_string())
+        );
+        assert_eq!(run("after", ""), Ok("9 2 6 5 8 3 7 4".to_string()));
+        assert_eq!(
+            run("cups", "1000"),
+            Ok("after 1: 2 5 4 6 7 10 11 12 13 14 ...".to_string())
+        );
+        assert_eq!(
+            run("move", "3").unwrap(),
+            "Move 3: after 1: 3 4 6 7 2 5 10 11 12 13 ..."
+        );
+        assert!(run("cups", "5").is_err());
+    }
+
+    #[test]
+    fn test_order() {
+        let cups = Cups::new("583741926", 9);
+        assert_eq!(cups.order(), "92658374");
+    }
+
+    proptest! {
+        #[test]
+        fn prop_make_move_keeps_a_ring(
+            labels in labels(),
+            total in 9..100usize,
+            moves in 0..200usize,
+        ) {
+            let mut cups = Cups::new(&labels, total);
+            for _ in 0..moves {
+                let current = cups.cur as u64;
+                let picked = cups.cups(cups.cur, 4).split_off(1);
+                let destination = cups.make_move();
+                prop_assert!(destination != current && !picked.contains(&destination));
+                prop_assert_eq!(cups.get_next(current), cups.cur as u64);
+
+                // every cup exactly once before coming back around
+                let mut ring = cups.cups(cups.cur, total + 1);
+                prop_assert_eq!(ring.pop(), Some(cups.cur as u64));
+                ring.sort_unstable();
+                prop_assert_eq!(ring, (1..=total as u64).collect::<Vec<u64>>());
+            }
+        }
+
+        #[test]
+        fn prop_make_move_like_naive(
+            labels in labels(),
+            total in 9..30usize,
+            moves in 0..100usize,
+        ) {
+            let mut cups = Cups::new(&labels, total);
+            for _ in 0..moves {
+                cups.make_move();
+            }
+            prop_assert_eq!(cups.cups(cups.cur, total), naive_moves(&labels, total as u64, moves));
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use aoc_common::{
+    hex::{self, Axial, Layout},
+    render, Answer, Automaton, Frame, Frames, Life, ParseError, Solution,
+};
+
+use std::{
+    collections::{HashMap, HashSet},
+    iter,
+};
+use tracing::debug;
+
+/// How many times each tile was flipped, by where it is from the reference tile.
+fn parse_tiles(input: &str) -> Result<HashMap<Axial, u64>, ParseError> {
+    let mut tiles: HashMap<Axial, u64> = HashMap::new();
+    for line in input.lines() {
+        let mut pos = Axial::default();
+        for dir in Layout::PointyTop.parse_path(input, line)? {
+            pos = pos + Layout::PointyTop.step(dir).unwrap();
+        }
+        *tiles.entry(pos).or_default() += 1;
+    }
+    Ok(tiles)
+}
+
+/// Black tiles with zero or more than 2 black neighbours turn white, white tiles with exactly 2
+/// black neighbours turn black.
+const ART_EXHIBIT: Life = Life {
+    birth: &[2],
+    survival: &[1, 2],
+};
+
+const DAYS: usize = 100;
+
+fn lobby(tiles: &HashMap<Axial, u64>) -> Automaton<hex::Plane> {
+    let black = tiles
+        .iter()
+        .filter(|(_, v)| *v % 2 == 1)
+        .map(|(pos, _)| *pos);
+    Automaton::new(hex::Plane, black)
+}
+
+/// The floor of the lobby day by day, with `#` for black tiles and `.` for white ones.
+fn exhibit(tiles: &HashMap<Axial, u64>) -> Frames<'_> {
+    let mut floor = lobby(tiles);
+    let mut day = 0;
+
+    Box::new(iter::from_fn(move || {
+        if day > DAYS {
+            return None;
+        }
+        if day > 0 {
+            floor.step(&ART_EXHIBIT);
+        }
+        let black: HashSet<Axial> = floor.alive().copied().collect();
+        let caption = format!("Day {}: {} black tiles", day, black.len());
+        day += 1;
+        Some(Frame::new(caption, render::plot_hexes(&black, '#', '.')))
+    }))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = HashMap<Axial, u64>;
+
+    fn parse(input: &str) -> Result<HashMap<Axial, u64>, ParseError> {
+        parse_tiles(input)
+    }
+
+    fn part1(tiles: &HashMap<Axial, u64>) -> Answer {
+        tiles.values().map(|v| v % 2).sum::<u64>().into()
+    }
+
+    fn part2(tiles: &HashMap<Axial, u64>) -> Answer {
+        let mut floor = lobby(tiles);
+        for day in 0..DAYS {
+            floor.step(&ART_EXHIBIT);
+            debug!("Day {}: {}", day + 1, floor.count());
+        }
+
+        floor.count().into()
+    }
+
+    fn animate(tiles: &HashMap<Axial, u64>, part: u32) -> Option<Frames<'_>> {
+        match part {
+            2 => Some(exhibit(tiles)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+    use proptest::prelude::*;
+
+    fn tiles() -> impl Strategy<Value = HashMap<Axial, u64>> {
+        prop::collection::hash_map((-6..6i64, -6..6i64), 1..3u64, 0..40).prop_map(|tiles| {
+            tiles
+                .into_iter()
+                .map(|((q, r), flips)| (Axial::new(q, r), flips))
+                .collect()
+        })
+    }
+
+    const PATH: &str = "(e|se|sw|w|nw|ne)";
+
+    #[test]
+    fn test() {
+        let input = Day24::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day24::part1(&input), 10);
+        assert_eq!(Day24::part2(&input), 2208);
+
+        let frames: Vec<Frame> = Day24::animate(&input, 2).unwrap().collect();
+        assert_eq!(frames.len(), 101);
+        assert_eq!(frames[10].caption, "Day 10: 37 black tiles");
+        assert!(Day24::animate(&input, 1).is_none());
+    }
+
+    #[test]
+    fn test_parse_tiles() {
+        // esenee identifies the tile you land on if you start at the reference tile
+        // and then move one tile east, one tile southeast, one tile northeast, and one tile east
+        let tiles = parse_tiles("esenee").unwrap();
+        assert_eq!(tiles.get(&Axial::new(3, 0)), Some(&1));
+
+        // a line like nwwswee flips the reference tile itself.
+        let tiles = parse_tiles("nwwswee\nnwwswee").unwrap();
+        assert_eq!(tiles.get(&Axial::new(0, 0)), Some(&2));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day24::parse("esenee\nnwwsxee").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 4, "sx"));
+
+        let err = Day24::parse("nwwswee\nesen").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 4, "n"));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_evolve_translation_invariant(
+            tiles in tiles(),
+            q in -50..50i64,
+            r in -50..50i64,
+            days in 0..12usize,
+        ) {
+            let shift = Axial::new(q, r);
+            let moved: HashMap<Axial, u64> =
+                tiles.iter().map(|(pos, flips)| (*pos + shift, *flips)).collect();
+            let (mut floor, mut moved_floor) = (lobby(&tiles), lobby(&moved));
+            floor.run(&ART_EXHIBIT, days);
+            moved_floor.run(&ART_EXHIBIT, days);
+
+            let expected: HashSet<Axial> = floor.alive().map(|pos| *pos + shift).collect();
+            prop_assert_eq!(moved_floor.alive().copied().collect::<HashSet<Axial>>(), expected);
+        }
+
+        #[test]
+        fn prop_path_order_irrelevant(
+            (steps, shuffled) in prop::collection::vec(PATH, 0..30)
+                .prop_flat_map(|steps| (Just(steps.clone()), Just(steps).prop_shuffle()))
+        ) {
+            prop_assert_eq!(
+                parse_tiles(&steps.concat()).unwrap(),
+                parse_tiles(&shuffled.concat()).unwrap()
+            );
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use aoc_common::{
+    math::{discrete_log, powmod},
+    number, Answer, ParseError, Solution,
+};
+
+const MODULUS: i64 = 20201227;
+const SUBJECT: i64 = 7;
+
+/// The key the card and door agree on: the door's public key transformed with the card's loop
+/// size, which is the discrete logarithm of the card's public key.
+fn encryption_key(card_pubkey: i64, door_pubkey: i64) -> i64 {
+    let card_loop = discrete_log(SUBJECT, card_pubkey, MODULUS).unwrap();
+    powmod(door_pubkey, card_loop, MODULUS)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = (i64, i64);
+
+    fn parse(input: &str) -> Result<(i64, i64), ParseError> {
+        let keys = input
+            .lines()
+            .map(|l| number(input, l.trim()))
+            .collect::<Result<Vec<i64>, _>>()?;
+        match keys[..] {
+            [card, door] => Ok((card, door)),
+            _ => Err(ParseError::new(
+                input,
+                input,
+                "two public keys, one per line",
+            )),
+        }
+    }
+
+    fn part1(&(card_pubkey, door_pubkey): &(i64, i64)) -> Answer {
+        encryption_key(card_pubkey, door_pubkey).into()
+    }
+
+    fn part2(_: &(i64, i64)) -> Answer {
+        Answer::Empty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        assert_eq!(encryption_key(5764801, 17807724), 14897079);
+        assert_eq!(
+            Day25::part1(&Day25::parse("5764801\n17807724").unwrap()),
+            14897079
+        );
+    }
+}
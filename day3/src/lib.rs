@@ -0,0 +1,71 @@
+use aoc_common::{Answer, Grid, ParseError, Solution};
+use tracing::debug;
+
+/// The map, `true` where there is a tree. It repeats to the right as far as needed.
+fn get_grid(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(
+        input,
+        input,
+        |c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            _ => None,
+        },
+        "'.' or '#'",
+    )
+}
+
+fn count_trees(grid: &Grid<bool>, down: usize, right: usize) -> u64 {
+    let mut x = 0;
+    let mut y = 0;
+    let mut result = 0;
+
+    while y < grid.height() {
+        if *grid.get_wrapping(x as i64, y as i64) {
+            result += 1;
+        }
+
+        x += right;
+        y += down;
+    }
+
+    debug!("down {}, right {}: {} trees", down, right, result);
+
+    result
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Grid<bool>;
+
+    fn parse(input: &str) -> Result<Grid<bool>, ParseError> {
+        get_grid(input)
+    }
+
+    fn part1(grid: &Grid<bool>) -> Answer {
+        count_trees(grid, 1, 3).into()
+    }
+
+    fn part2(grid: &Grid<bool>) -> Answer {
+        (count_trees(grid, 1, 1)
+            * count_trees(grid, 1, 3)
+            * count_trees(grid, 1, 5)
+            * count_trees(grid, 1, 7)
+            * count_trees(grid, 2, 1))
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day3::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day3::part1(&input), 7);
+        assert_eq!(Day3::part2(&input), 336);
+    }
+}
@@ -0,0 +1,163 @@
+use aoc_common::{groups, Answer, ParseError, Solution};
+use std::collections::HashMap;
+
+extern crate regex;
+use regex::Regex;
+
+use std::collections::HashSet;
+
+fn get_maps(input: &str) -> Result<Vec<HashMap<String, String>>, ParseError> {
+    let mut maps = vec![];
+    for group in groups(input) {
+        let mut map: HashMap<String, String> = HashMap::new();
+        for line in group {
+            for pair in line.trim().split(" ") {
+                let (key, value) = pair
+                    .split_once(':')
+                    .ok_or_else(|| ParseError::new(input, pair, "a field like 'ecl:gry'"))?;
+                map.insert(key.to_string(), value.to_string());
+            }
+        }
+        maps.push(map);
+    }
+
+    Ok(maps)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<HashMap<String, String>>;
+
+    fn parse(input: &str) -> Result<Vec<HashMap<String, String>>, ParseError> {
+        get_maps(input)
+    }
+
+    fn part1(maps: &Vec<HashMap<String, String>>) -> Answer {
+        let mut result = 0;
+
+        for map in maps {
+            let cid = if map.contains_key("cid") { 1 } else { 0 };
+
+            if map.keys().len() == 7 + cid {
+                result += 1;
+            }
+        }
+
+        result.into()
+    }
+
+    fn part2(maps: &Vec<HashMap<String, String>>) -> Answer {
+        maps.iter().filter(|map| valid(map)).count().into()
+    }
+}
+
+fn valid(map: &HashMap<String, String>) -> bool {
+    let cid = if map.contains_key("cid") { 1 } else { 0 };
+
+    map.keys().len() == 7 + cid
+        && validate_year(&map["byr"], 1920, 2002)
+        && validate_year(&map["iyr"], 2010, 2020)
+        && validate_year(&map["eyr"], 2020, 2030)
+        && validate_hgt(&map["hgt"])
+        && validate_ecl(&map["ecl"])
+        && validate_hcl(&map["hcl"])
+        && validate_pid(&map["pid"])
+}
+
+// byr (Birth Year) - four digits; at least 1920 and at most 2002.
+// iyr (Issue Year) - four digits; at least 2010 and at most 2020.
+// eyr (Expiration Year) - four digits; at least 2020 and at most 2030.
+fn validate_year(byr: &str, low: i32, high: i32) -> bool {
+    let digit = byr.parse::<i32>().unwrap();
+    digit >= low && digit <= high
+}
+
+/*
+hgt (Height) - a number followed by either cm or in:
+If cm, the number must be at least 150 and at most 193.
+If in, the number must be at least 59 and at most 76.
+*/
+fn validate_hgt(hgt: &str) -> bool {
+    let re = Regex::new(r"([0-9]+)(cm|in)").unwrap();
+
+    if let Some(cap) = re.captures(hgt) {
+        let hgt = cap[1].parse::<i32>().unwrap();
+
+        match &cap[2] {
+            "cm" => (150..=193).contains(&hgt),
+            "in" => (59..=76).contains(&hgt),
+            _ => false,
+        }
+    } else {
+        false
+    }
+}
+
+// hcl (Hair Color) - a # followed by exactly six characters 0-9 or a-f.
+fn validate_hcl(hcl: &str) -> bool {
+    let re = Regex::new(r"^\#([0-9a-f]{6})$").unwrap();
+
+    re.is_match(hcl)
+}
+
+// ecl (Eye Color) - exactly one of: amb blu brn gry grn hzl oth.
+fn validate_ecl(ecl: &str) -> bool {
+    let colors: HashSet<&str> = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]
+        .iter()
+        .cloned()
+        .collect();
+
+    colors.contains(ecl.trim())
+}
+
+// pid (Passport ID) - a nine-digit number, including leading zeroes.
+fn validate_pid(pid: &str) -> bool {
+    let re = Regex::new(r"^([0-9]{9})$").unwrap();
+
+    re.is_match(pid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day4::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day4::part1(&input), 2);
+        let input = Day4::parse(&string_from_file("example2.txt")).unwrap();
+        assert_eq!(Day4::part2(&input), 0);
+        let input = Day4::parse(&string_from_file("example3.txt")).unwrap();
+        assert_eq!(Day4::part2(&input), 4);
+    }
+
+    #[test]
+    fn test_validate_byr() {
+        assert!(validate_year("2002", 1920, 2002));
+        assert!(!validate_year("2003", 1920, 2002));
+        assert!(!validate_year("200", 1920, 2002));
+        assert!(!validate_year("20009", 1920, 2002));
+    }
+
+    #[test]
+    fn test_validate_hgt() {
+        assert!(validate_hgt("60in"));
+        assert!(validate_hgt("190cm"));
+        assert!(!validate_hgt("190in"));
+        assert!(!validate_hgt("190"));
+    }
+
+    #[test]
+    fn test_validate_ecl() {
+        assert!(validate_ecl("brn"));
+        assert!(!validate_ecl("wat"));
+    }
+
+    #[test]
+    fn test_validate_pid() {
+        assert!(validate_pid("000000001"));
+        assert!(!validate_pid("0123456789"));
+    }
+}
@@ -1,8 +1,5 @@
-use std::{
-    fs::File,
-    io::{prelude::*, BufReader},
-    path::Path,
-};
+use aoc_common::{Answer, ParseError, Solution};
+use tracing::trace;
 
 use std::collections::HashSet;
 
@@ -27,61 +24,96 @@ fn code_to_id(line: &str) -> u64 {
     let mut row = Range { lo: 0, hi: 127 };
     for op in line[0..7].chars() {
         reduce(&mut row, op);
-        //println!("{} {} {}", op, row.lo, row.hi);
+        trace!("{} {} {}", op, row.lo, row.hi);
     }
     let mut col = Range { lo: 0, hi: 7 };
     for op in line[7..10].chars() {
         reduce(&mut col, op);
-        //println!("{} {} {}", op, col.lo, col.hi);
+        trace!("{} {} {}", op, col.lo, col.hi);
     }
     (row.lo * 8) + col.hi
 }
 
-fn solve1(filename: &str) -> u64 {
-    lines_from_file(filename)
-        .iter()
-        .map(|line| code_to_id(&line))
-        .max()
-        .unwrap()
-}
-
-fn solve2(filename: &str) -> u64 {
-    let mut seats: HashSet<u64> = HashSet::new();
-
-    for row in 0..128 {
-        for col in 0..8 {
-            seats.insert(row * 8 + col);
-        }
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                let valid = line.chars().count() == 10
+                    && line.chars().enumerate().all(|(i, c)| match i {
+                        0..=6 => c == 'F' || c == 'B',
+                        _ => c == 'L' || c == 'R',
+                    });
+                if valid {
+                    Ok(code_to_id(line))
+                } else {
+                    Err(ParseError::new(
+                        input,
+                        line,
+                        "a boarding pass like 'FBFBBFFRLR'",
+                    ))
+                }
+            })
+            .collect()
     }
 
-    for line in lines_from_file(filename) {
-        seats.remove(&code_to_id(&line));
+    fn part1(ids: &Vec<u64>) -> Answer {
+        (*ids.iter().max().unwrap()).into()
     }
 
-    *seats.iter().filter(|&x| *x > 100 && *x < 900).max().unwrap()
-}
+    fn part2(ids: &Vec<u64>) -> Answer {
+        let mut seats: HashSet<u64> = HashSet::new();
 
-fn lines_from_file(filename: impl AsRef<Path>) -> Vec<String> {
-    let file = File::open(filename).expect("no such file");
-    let buf = BufReader::new(file);
-    buf.lines()
-        .map(|l| l.expect("Could not parse line"))
-        .collect()
-}
+        for row in 0..128 {
+            for col in 0..8 {
+                seats.insert(row * 8 + col);
+            }
+        }
+
+        for id in ids {
+            seats.remove(id);
+        }
 
-fn main() {
-    println!("{}", solve1("../input/2020/day5.txt"));
-    println!("{}", solve2("../input/2020/day5.txt"));
+        (*seats
+            .iter()
+            .filter(|&x| *x > 100 && *x < 900)
+            .max()
+            .unwrap())
+        .into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::string_from_file;
+    use proptest::prelude::*;
+
+    /// The boarding pass of seat `id`: its row in binary with F and B, then its column with L and
+    /// R.
+    fn id_to_code(id: u64) -> String {
+        (0..10)
+            .rev()
+            .map(|bit| match (bit >= 3, id >> bit & 1) {
+                (true, 0) => 'F',
+                (true, _) => 'B',
+                (false, 0) => 'L',
+                (false, _) => 'R',
+            })
+            .collect()
+    }
 
     #[test]
     fn test() {
-        assert_eq!(solve1("example.txt"), 820);
-        //assert_eq!(solve2("example.txt"), 336);
+        let input = Day5::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day5::part1(&input), 820);
+        // the example has no free seat, so take a full plane but for seat 500
+        let ids = (101..900).filter(|id| *id != 500).collect();
+        assert_eq!(Day5::part2(&ids), 500);
     }
 
     #[test]
@@ -161,4 +193,22 @@ mod tests {
         assert_eq!(code_to_id("FFFBBBFRRR"), 119);
         assert_eq!(code_to_id("BBFFBBFRLL"), 820);
     }
+
+    proptest! {
+        #[test]
+        fn prop_code_round_trip(id in 0..1024u64) {
+            let code = id_to_code(id);
+            prop_assert_eq!(code_to_id(&code), id);
+            prop_assert_eq!(Day5::parse(&code).unwrap(), vec![id]);
+        }
+
+        #[test]
+        fn prop_part2_finds_the_gap(seat in 101..899u64, first in 0..100u64, last in 900..1024u64) {
+            let ids: Vec<u64> = (first..=last).filter(|id| *id != seat).collect();
+            let input: Vec<String> = ids.iter().map(|id| id_to_code(*id)).collect();
+            let parsed = Day5::parse(&input.join("\n")).unwrap();
+            prop_assert_eq!(Day5::part1(&parsed), Answer::from(last));
+            prop_assert_eq!(Day5::part2(&parsed), Answer::from(seat));
+        }
+    }
 }
@@ -0,0 +1,69 @@
+use aoc_common::{groups, Answer, ParseError, Solution};
+
+use std::collections::HashSet;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Vec<String>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<String>>, ParseError> {
+        let groups = groups(input);
+        for line in groups.iter().flatten() {
+            if let Some((i, chr)) = line.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+                return Err(ParseError::new(
+                    input,
+                    &line[i..i + chr.len_utf8()],
+                    "a question from 'a' to 'z'",
+                ));
+            }
+        }
+
+        Ok(groups
+            .iter()
+            .map(|group| group.iter().map(|l| l.to_string()).collect())
+            .collect())
+    }
+
+    fn part1(groups: &Vec<Vec<String>>) -> Answer {
+        groups
+            .iter()
+            .map(|group| group.concat().chars().collect::<HashSet<char>>().len() as u64)
+            .sum::<u64>()
+            .into()
+    }
+
+    fn part2(groups: &Vec<Vec<String>>) -> Answer {
+        groups
+            .iter()
+            .map(|group| {
+                let mut sets = group
+                    .iter()
+                    .map(|set| set.chars().collect::<HashSet<char>>());
+
+                sets.next()
+                    .map(|set| {
+                        sets.fold(set, |set1, set2| {
+                            set1.intersection(&set2).copied().collect::<HashSet<char>>()
+                        })
+                    })
+                    .unwrap()
+                    .len() as u64
+            })
+            .sum::<u64>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day6::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day6::part1(&input), 11);
+        assert_eq!(Day6::part2(&input), 6);
+    }
+}
@@ -0,0 +1,227 @@
+use aoc_common::{
+    captures, number, Answer, Digraph, Explore, Explorer, NodeId, ParseError, Solution,
+};
+
+extern crate regex;
+use regex::Regex;
+
+#[derive(PartialEq, Eq, Hash, Debug)]
+pub struct Edge {
+    amount: u64,
+    color: String,
+}
+
+fn parse_line(input: &str, line: &str) -> Result<(String, Vec<Edge>), ParseError> {
+    let splits: Vec<&str> = line.split("contain").collect();
+    if splits.len() != 2 {
+        return Err(ParseError::new(
+            input,
+            line,
+            "a rule like 'light red bags contain 1 bright white bag.'",
+        ));
+    }
+    let bag_re = Regex::new(r"([a-z ]+)+ bag[s]?\.?").unwrap();
+    let bag = captures(input, splits[0], &bag_re, "a bag like 'light red bags'")?[1].to_string();
+
+    if splits[1] == " no other bags." {
+        Ok((bag, vec![]))
+    } else {
+        let target_re = Regex::new(r"(\d) ([a-z ]+)+ bag[s]?\.?").unwrap();
+        let mut targets = vec![];
+        for target in splits[1].split(",") {
+            let cap = captures(
+                input,
+                target.trim(),
+                &target_re,
+                "an amount of bags like '2 muted yellow bags'",
+            )?;
+            targets.push(Edge {
+                amount: number(input, &cap[1])?,
+                color: cap[2].to_string(),
+            });
+        }
+        Ok((bag, targets))
+    }
+}
+
+const SHINY_GOLD: &str = "shiny gold";
+
+fn build_graph(input: &str) -> Result<Digraph<u64>, ParseError> {
+    let mut graph = Digraph::new();
+
+    for line in input.lines() {
+        let (bag, targets) = parse_line(input, line)?;
+        let from = graph.node(&bag);
+        for edge in targets {
+            let to = graph.node(&edge.color);
+            graph.add_edge(from, to, edge.amount);
+        }
+    }
+
+    Ok(graph)
+}
+
+/// How many bags a `bag` holds, counting the bags inside those bags too.
+fn bags_inside(graph: &Digraph<u64>, bag: NodeId) -> u64 {
+    graph.fold(bag, |_, inside| {
+        inside
+            .iter()
+            .map(|(bags, amount)| *amount * (1 + bags))
+            .sum::<u64>()
+    })
+}
+
+/// Questions about single bags.
+struct Bags<'a> {
+    graph: &'a Digraph<u64>,
+    reversed: Digraph<u64>,
+}
+
+impl Bags<'_> {
+    fn find(&self, bag: &str) -> Result<NodeId, String> {
+        self.graph
+            .id(bag.trim())
+            .ok_or_else(|| format!("There are no rules for {} bags", bag.trim()))
+    }
+}
+
+impl Explore for Bags<'_> {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("contains <bag>", "which bags end up holding a <bag>"),
+            (
+                "inside <bag>",
+                "what a <bag> holds, and how many bags that is in all",
+            ),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &str) -> Option<Result<String, String>> {
+        let result = match command {
+            "contains" => self.find(args).map(|bag| {
+                let mut outer: Vec<&str> = self.reversed.dfs(bag)[1..]
+                    .iter()
+                    .map(|id| self.graph.name(*id))
+                    .collect();
+                outer.sort_unstable();
+                format!("{} bags: {}", outer.len(), outer.join(", "))
+            }),
+            "inside" => self.find(args).map(|bag| {
+                let inner: Vec<String> = self
+                    .graph
+                    .edges(bag)
+                    .iter()
+                    .map(|(id, amount)| format!("{} {}", amount, self.graph.name(*id)))
+                    .collect();
+                format!(
+                    "{} bags: {}",
+                    bags_inside(self.graph, bag),
+                    inner.join(", ")
+                )
+            }),
+            _ => return None,
+        };
+        Some(result)
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Digraph<u64>;
+
+    fn parse(input: &str) -> Result<Digraph<u64>, ParseError> {
+        build_graph(input)
+    }
+
+    fn part1(graph: &Digraph<u64>) -> Answer {
+        match graph.id(SHINY_GOLD) {
+            // every bag that can reach shiny gold, apart from shiny gold itself
+            Some(gold) => (graph.reversed().dfs(gold).len() - 1).into(),
+            None => 0.into(),
+        }
+    }
+
+    fn part2(graph: &Digraph<u64>) -> Answer {
+        match graph.id(SHINY_GOLD) {
+            Some(gold) => bags_inside(graph, gold).into(),
+            None => 0.into(),
+        }
+    }
+
+    fn explore(graph: &Digraph<u64>) -> Option<Explorer<'_>> {
+        Some(Box::new(Bags {
+            graph,
+            reversed: graph.reversed(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day7::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day7::part1(&input), 4);
+        assert_eq!(Day7::part2(&input), 32);
+        let input = Day7::parse(&string_from_file("example2.txt")).unwrap();
+        assert_eq!(Day7::part2(&input), 126);
+    }
+
+    #[test]
+    fn test_explore() {
+        let input = Day7::parse(&string_from_file("example.txt")).unwrap();
+        let mut bags = Day7::explore(&input).unwrap();
+        assert_eq!(
+            bags.run("contains", "shiny gold"),
+            Some(Ok(
+                "4 bags: bright white, dark orange, light red, muted yellow".to_string()
+            ))
+        );
+        assert_eq!(
+            bags.run("inside", "shiny gold"),
+            Some(Ok("32 bags: 1 dark olive, 2 vibrant plum".to_string()))
+        );
+        assert_eq!(
+            bags.run("inside", "plain red"),
+            Some(Err("There are no rules for plain red bags".to_string()))
+        );
+        assert_eq!(bags.run("step", ""), None);
+    }
+
+    #[test]
+    fn test_parse_line() {
+        let line = "mirrored coral bags contain 4 shiny salmon bags, 1 light orange bag, 4 faded yellow bags, 5 shiny maroon bags.";
+        let (bag, targets) = parse_line(line, line).unwrap();
+        assert_eq!(bag, "mirrored coral");
+        assert_eq!(targets.len(), 4);
+
+        assert_eq!(targets[0].amount, 4);
+        assert_eq!(targets[0].color, "shiny salmon");
+        assert_eq!(targets[1].amount, 1);
+        assert_eq!(targets[1].color, "light orange");
+        assert_eq!(targets[2].amount, 4);
+        assert_eq!(targets[2].color, "faded yellow");
+        assert_eq!(targets[3].amount, 5);
+        assert_eq!(targets[3].color, "shiny maroon");
+    }
+
+    #[test]
+    fn test_parse_line_leaf() {
+        let line = "dotted black bags contain no other bags.";
+        let (bag, targets) = parse_line(line, line).unwrap();
+        assert_eq!(bag, "dotted black");
+        assert_eq!(targets.len(), 0);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "light red bags contain 1 bright white bag.\nfaded blue bags contain two dotted black bags.";
+        let err = Day7::parse(input).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 25));
+        assert_eq!(err.token, "two dotted black bags.");
+    }
+}
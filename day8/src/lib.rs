@@ -0,0 +1,268 @@
+use aoc_common::{explore::arg, number, Answer, Explore, Explorer, ParseError, Solution};
+use tracing::{debug, trace};
+
+#[derive(Debug, Clone)]
+pub struct Operation {
+    instr: String,
+    operand: i64,
+}
+
+struct Runner<'a> {
+    code: &'a [Operation],
+    acc: i64,
+    pc: usize,
+}
+
+impl<'a> Runner<'a> {
+    fn run(&mut self) -> bool {
+        let mut visited_addresses: Vec<bool> = vec![false; self.code.len()];
+
+        loop {
+            if self.pc >= self.code.len() {
+                debug!("Terminating normally, acc: {}", self.acc);
+                return true;
+            }
+            if visited_addresses[self.pc] {
+                debug!("Infinite loop detected at {}, acc: {}", self.pc, self.acc);
+                return false;
+            }
+            visited_addresses[self.pc] = true;
+            self.step();
+        }
+    }
+
+    /// Runs the instruction at `pc`, which must be in the program.
+    fn step(&mut self) {
+        let pc = self.pc;
+        let op = &self.code[pc];
+        match op.instr.as_ref() {
+            "acc" => {
+                self.acc += op.operand;
+                self.pc += 1;
+            }
+            "jmp" => self.pc = (self.pc as i64 + op.operand) as usize,
+            "nop" => self.pc += 1,
+            _ => panic!("Unknown op: {:?}", op),
+        }
+
+        trace!("{}: {:?} -> acc: {}, pc: {}", pc, op, self.acc, self.pc);
+    }
+}
+
+/// The boot code in a debugger, an instruction at a time.
+struct Debugger<'a> {
+    runner: Runner<'a>,
+}
+
+impl Explore for Debugger<'_> {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("step [N]", "run the next N instructions, 1 by default"),
+            (
+                "run",
+                "run until the program ends or is about to repeat an instruction",
+            ),
+            ("reset", "start again from the first instruction with acc 0"),
+        ]
+    }
+
+    fn run(&mut self, command: &str, args: &str) -> Option<Result<String, String>> {
+        let runner = &mut self.runner;
+        let result = match command {
+            "step" => arg(args, 1).map(|steps: usize| {
+                let mut shown = vec![];
+                for _ in 0..steps {
+                    let pc = runner.pc;
+                    match runner.code.get(pc) {
+                        Some(op) => {
+                            runner.step();
+                            shown.push(format!(
+                                "{}: {} {:+} -> acc {}, pc {}",
+                                pc, op.instr, op.operand, runner.acc, runner.pc
+                            ));
+                        }
+                        None => {
+                            shown.push(format!("The program has ended, acc {}", runner.acc));
+                            break;
+                        }
+                    }
+                }
+                shown.join("\n")
+            }),
+            "run" => Ok(if runner.run() {
+                format!("The program ended, acc {}", runner.acc)
+            } else {
+                format!("About to repeat {}, acc {}", runner.pc, runner.acc)
+            }),
+            "reset" => {
+                runner.acc = 0;
+                runner.pc = 0;
+                Ok("acc 0, pc 0".to_string())
+            }
+            _ => return None,
+        };
+        Some(result)
+    }
+}
+
+/*
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6
+*/
+fn parse_opcodes(input: &str) -> Result<Vec<Operation>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (instr, operand) = line
+                .split_once(' ')
+                .filter(|(instr, _)| ["acc", "jmp", "nop"].contains(instr))
+                .ok_or_else(|| ParseError::new(input, line, "an operation like 'acc +1'"))?;
+            Ok(Operation {
+                instr: instr.to_string(),
+                operand: number(input, operand)?,
+            })
+        })
+        .collect()
+}
+
+fn brute_switch_instr(from: &str, to: &str, operations: &mut Vec<Operation>) -> Option<i64> {
+    let mut idx = 0;
+
+    while idx <= operations.len() {
+        while idx <= operations.len() {
+            let op = &operations[idx];
+            if op.instr == from {
+                operations[idx] = Operation {
+                    instr: to.to_string(),
+                    operand: op.operand,
+                };
+                break;
+            }
+            idx += 1;
+        }
+
+        let mut runner = Runner {
+            code: operations,
+            acc: 0,
+            pc: 0,
+        };
+
+        if runner.run() {
+            return Some(runner.acc);
+        } else {
+            operations[idx] = Operation {
+                instr: from.to_string(),
+                operand: operations[idx].operand,
+            };
+        }
+        idx += 1;
+    }
+
+    None
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<Operation>;
+
+    fn parse(input: &str) -> Result<Vec<Operation>, ParseError> {
+        parse_opcodes(input)
+    }
+
+    fn part1(operations: &Vec<Operation>) -> Answer {
+        let mut runner = Runner {
+            code: operations,
+            acc: 0,
+            pc: 0,
+        };
+
+        runner.run();
+        runner.acc.into()
+    }
+
+    fn part2(operations: &Vec<Operation>) -> Answer {
+        let mut operations = operations.clone();
+
+        if let Some(acc) = brute_switch_instr("jmp", "nop", &mut operations) {
+            return acc.into();
+        }
+        if let Some(acc) = brute_switch_instr("nop", "jmp", &mut operations) {
+            return acc.into();
+        }
+
+        panic!("Could not find solution");
+    }
+
+    fn explore(operations: &Vec<Operation>) -> Option<Explorer<'_>> {
+        Some(Box::new(Debugger {
+            runner: Runner {
+                code: operations,
+                acc: 0,
+                pc: 0,
+            },
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let input = Day8::parse(&string_from_file("example.txt")).unwrap();
+        assert_eq!(Day8::part1(&input), 5);
+        assert_eq!(Day8::part2(&input), 8);
+        let input = Day8::parse(&string_from_file("example2.txt")).unwrap();
+        assert_eq!(Day8::part1(&input), 8);
+    }
+
+    #[test]
+    fn test_explore() {
+        let input = Day8::parse(&string_from_file("example.txt")).unwrap();
+        let mut debugger = Day8::explore(&input).unwrap();
+        let mut run = |command: &str, args: &str| debugger.run(command, args).unwrap().unwrap();
+        assert_eq!(
+            run("step", "3"),
+            "0: nop +0 -> acc 0, pc 1\n1: acc +1 -> acc 1, pc 2\n2: jmp +4 -> acc 1, pc 6"
+        );
+        assert_eq!(run("run", ""), "About to repeat 6, acc 6");
+        assert_eq!(run("reset", ""), "acc 0, pc 0");
+        assert_eq!(run("run", ""), "About to repeat 1, acc 5");
+
+        let input = Day8::parse("acc +2\nnop +0").unwrap();
+        let mut debugger = Day8::explore(&input).unwrap();
+        assert_eq!(
+            debugger.run("step", "3"),
+            Some(Ok(
+                "0: acc +2 -> acc 2, pc 1\n1: nop +0 -> acc 2, pc 2\nThe program has ended, acc 2"
+                    .to_string()
+            ))
+        );
+        assert!(debugger.run("step", "x").unwrap().is_err());
+        assert_eq!(debugger.run("flip", "1"), None);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = Day8::parse("nop +0\nacc +1\njmp four").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 5));
+        assert_eq!(
+            err.to_string(),
+            "line 3, column 5: expected a number, found 'four'"
+        );
+
+        let err = Day8::parse("nop +0\nmul +2").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "an operation like 'acc +1'");
+    }
+}
@@ -0,0 +1,103 @@
+use aoc_common::{ints, Answer, Params, ParseError, Solution};
+
+#[allow(dead_code)]
+struct Pair {
+    i1: i64,
+    i2: i64,
+}
+
+fn find_first_not_sum_previous(numbers: &[i64], preamble: usize) -> i64 {
+    let mut idx = 0;
+
+    while idx + preamble < numbers.len() {
+        let mut sums: Vec<Pair> = vec![];
+        for i in &numbers[idx..idx + preamble] {
+            for j in &numbers[idx..idx + preamble] {
+                if i != j && i + j == numbers[idx + preamble] {
+                    sums.push(Pair { i1: *i, i2: *j });
+                }
+            }
+        }
+
+        if sums.is_empty() {
+            return numbers[idx + preamble];
+        }
+        idx += 1;
+    }
+
+    panic!("Could not find number in list that met requirements!")
+}
+
+fn find_min_max_in_cont_sum(numbers: &[i64], wanted_sum: i64) -> i64 {
+    for start in 0..numbers.len() {
+        let mut sum = 0;
+        let mut min = i64::MAX;
+        let mut max = i64::MIN;
+        for num in &numbers[start..] {
+            if num < &min {
+                min = *num;
+            } else if num > &max {
+                max = *num;
+            }
+            sum += num;
+            if sum == wanted_sum {
+                return min + max;
+            }
+        }
+    }
+    panic!("Could not find continous range that met requirements!")
+}
+
+#[derive(Debug)]
+pub struct Xmas {
+    numbers: Vec<i64>,
+    preamble: usize,
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Xmas;
+
+    fn parse(input: &str) -> Result<Xmas, ParseError> {
+        Day9::parse_with(input, &Params::new())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Xmas, ParseError> {
+        Ok(Xmas {
+            numbers: ints(input)?,
+            preamble: params.get("preamble").unwrap_or(25),
+        })
+    }
+
+    fn part1(xmas: &Xmas) -> Answer {
+        find_first_not_sum_previous(&xmas.numbers, xmas.preamble).into()
+    }
+
+    fn part2(xmas: &Xmas) -> Answer {
+        let invalid = find_first_not_sum_previous(&xmas.numbers, xmas.preamble);
+        find_min_max_in_cont_sum(&xmas.numbers, invalid).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::string_from_file;
+
+    #[test]
+    fn test() {
+        let mut params = Params::new();
+        params.set("preamble", 5);
+        let input = Day9::parse_with(&string_from_file("example.txt"), &params).unwrap();
+        assert_eq!(Day9::part1(&input), 127);
+        assert_eq!(Day9::part2(&input), 62);
+
+        // the number that breaks the rule can be the last one
+        let example = string_from_file("example.txt");
+        let until_invalid = &example[..example.find("127").unwrap() + 3];
+        let input = Day9::parse_with(until_invalid, &params).unwrap();
+        assert_eq!(Day9::part1(&input), 127);
+        assert_eq!(Day9::part2(&input), 62);
+    }
+}
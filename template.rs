@@ -1,37 +1,34 @@
-use std::{
-    fs::File,
-    io::{prelude::*, BufReader},
-    path::Path,
-};
+//! https://adventofcode.com/YEAR/day/N, with the input in input/YEAR/dayN.txt
+use aoc_common::{Answer, ParseError, Solution};
 
-fn solve1(filename: &str) -> u64 {
-    0
-}
+pub struct DayN;
 
-fn solve2(filename: &str) -> u64 {
-    0
-}
+impl Solution for DayN {
+    type Input = Vec<String>;
 
-fn lines_from_file(filename: impl AsRef<Path>) -> Vec<String> {
-    let file = File::open(filename).expect("no such file");
-    let buf = BufReader::new(file);
-    buf.lines()
-        .map(|l| l.expect("Could not parse line"))
-        .collect()
-}
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(|l| l.to_string()).collect())
+    }
 
-fn main() {
-    println!("{}", solve1("../input/2020/day1.txt"));
-    println!("{}", solve2("../input/2020/day1.txt"));
+    fn part1(_lines: &Vec<String>) -> Answer {
+        Answer::Empty
+    }
+
+    fn part2(_lines: &Vec<String>) -> Answer {
+        Answer::Empty
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_common::string_from_file;
 
     #[test]
     fn test() {
-        assert_eq!(solve1("example.txt"), 25);
-        //assert_eq!(solve2("example.txt"), 286);
+        let input = DayN::parse(&string_from_file("example.txt")).unwrap();
+        // the answers to the example, once solved
+        assert_eq!(DayN::part1(&input), Answer::Empty);
+        assert_eq!(DayN::part2(&input), Answer::Empty);
     }
 }